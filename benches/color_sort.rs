@@ -0,0 +1,36 @@
+//! Timing for the color sorting transforms over the full block table.
+//!
+//! Run with `cargo bench --bench color_sort` and compare against
+//! `cargo bench --bench color_sort --features parallel`.
+
+use blockpedia::{AllBlocks, ExtendedColorData};
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 10;
+
+fn time<F: FnMut() -> usize>(name: &str, mut f: F) {
+    let mut total = Duration::ZERO;
+    let mut len = 0;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        len = std::hint::black_box(f());
+        total += start.elapsed();
+    }
+    println!(
+        "{:<28} {:>10.2?} per run ({} blocks)",
+        name,
+        total / ITERATIONS,
+        len
+    );
+}
+
+fn main() {
+    let reference = ExtendedColorData::from_rgb(200, 60, 40);
+
+    time("sort_by_color_similarity", || {
+        AllBlocks::new().sort_by_color_similarity(reference).len()
+    });
+    time("sort_by_color_gradient", || {
+        AllBlocks::new().with_color().sort_by_color_gradient().len()
+    });
+}
@@ -1,6 +1,6 @@
 use anyhow::{Context, Result};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::io::Write;
@@ -26,6 +26,15 @@ mod data_sources_build {
         pub bedrock_default_state: Option<HashMap<String, String>>,
     }
 
+    impl UnifiedBlockData {
+        /// Find default_state entries that don't fit the declared properties
+        pub fn invalid_default_state(&self) -> Vec<(&str, &str)> {
+            invalid_default_state(&self.properties, &self.default_state)
+        }
+    }
+
+    include!("src/default_state_check.rs");
+
     /// Trait for different data source adapters in build script
     pub trait DataSourceAdapter {
         fn name(&self) -> &'static str;
@@ -123,6 +132,12 @@ mod data_sources_build {
                 if let Some(resistance) = block_obj.get("resistance") {
                     extra_properties.insert("resistance".to_string(), resistance.clone());
                 }
+                if let Some(diggable) = block_obj.get("diggable") {
+                    extra_properties.insert("diggable".to_string(), diggable.clone());
+                }
+                if let Some(display_name) = block_obj.get("displayName") {
+                    extra_properties.insert("displayName".to_string(), display_name.clone());
+                }
 
                 unified_blocks.push(UnifiedBlockData {
                     id,
@@ -1334,6 +1349,108 @@ fn setup_fetchers() -> FetcherRegistry {
     FetcherRegistry::new()
 }
 
+/// Load the optional block allowlist named by `BLOCKPEDIA_BLOCK_ALLOWLIST`.
+/// The file contains block IDs separated by newlines or commas; `#` starts a comment line.
+fn load_block_allowlist() -> Result<Option<HashSet<String>>> {
+    let path = match env::var("BLOCKPEDIA_BLOCK_ALLOWLIST") {
+        Ok(path) if !path.trim().is_empty() => path,
+        _ => return Ok(None),
+    };
+
+    println!("cargo:rerun-if-changed={}", path);
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read block allowlist from {}", path))?;
+
+    let allowlist: HashSet<String> = contents
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(|line| line.split(','))
+        .map(|id| id.trim())
+        .filter(|id| !id.is_empty())
+        .map(|id| id.to_string())
+        .collect();
+
+    if allowlist.is_empty() {
+        anyhow::bail!("Block allowlist {} does not list any block IDs", path);
+    }
+
+    Ok(Some(allowlist))
+}
+
+/// Keep only the allowlisted entries, failing if the allowlist names a block the source lacks
+fn retain_allowlisted<T>(
+    items: Vec<T>,
+    allowlist: &HashSet<String>,
+    id_of: impl Fn(&T) -> &str,
+) -> Result<Vec<T>> {
+    let available: HashSet<&str> = items.iter().map(&id_of).collect();
+    let mut missing: Vec<&str> = allowlist
+        .iter()
+        .map(|id| id.as_str())
+        .filter(|id| !available.contains(id))
+        .collect();
+
+    if !missing.is_empty() {
+        missing.sort();
+        anyhow::bail!(
+            "Block allowlist references blocks not found in the data source: {}",
+            missing.join(", ")
+        );
+    }
+
+    let total = items.len();
+    let kept: Vec<T> = items
+        .into_iter()
+        .filter(|item| allowlist.contains(id_of(item)))
+        .collect();
+
+    println!(
+        "cargo:warning=Block allowlist kept {} blocks, dropped {}",
+        kept.len(),
+        total - kept.len()
+    );
+
+    Ok(kept)
+}
+
+/// Report a property that has no allowed values; such properties are left out of the table
+/// since no state could ever satisfy them
+fn warn_empty_property(block_id: &str, prop_name: &str) {
+    println!(
+        "cargo:warning=Dropping property '{}' of {}: no allowed values",
+        prop_name, block_id
+    );
+}
+
+/// Report a default_state entry that doesn't match the block's properties; such entries are
+/// left out of the table so `BlockState::from_default` never yields an invalid state
+fn warn_invalid_default(block_id: &str, property: &str, value: &str) {
+    println!(
+        "cargo:warning=Dropping default {}={} of {}: not a valid property value",
+        property, value, block_id
+    );
+}
+
+/// Derive survival obtainability from block data
+///
+/// Unbreakable (negative hardness) and non-diggable blocks can never be collected in
+/// survival. Breakable blocks may still be unobtainable (e.g. no drops without silk
+/// touch), which the data can't tell us, so those are left undecided.
+fn obtainable_from_data(hardness: Option<&Value>, diggable: Option<&Value>) -> Option<bool> {
+    let unbreakable = hardness.and_then(|h| h.as_f64()).is_some_and(|h| h < 0.0);
+    let not_diggable = diggable.and_then(|d| d.as_bool()) == Some(false);
+    if unbreakable || not_diggable {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Mining hardness from block data; negative values mark unbreakable blocks
+fn hardness_from_data(hardness: Option<&Value>) -> Option<f32> {
+    hardness.and_then(|h| h.as_f64()).map(|h| h as f32)
+}
+
 /// Extract block IDs from JSON in either format
 fn get_block_ids_from_json(json: &Value) -> Result<Vec<String>> {
     let mut block_ids = Vec::new();
@@ -1366,9 +1483,11 @@ fn main() -> Result<()> {
     let out_dir = env::var("OUT_DIR").unwrap();
 
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/default_state_check.rs");
     println!("cargo:rerun-if-env-changed=BLOCKPEDIA_DATA_SOURCE");
     println!("cargo:rerun-if-env-changed=BLOCKPEDIA_USE_TEST_DATA");
     println!("cargo:rerun-if-env-changed=BLOCKPEDIA_VERSION_JSON_SHA");
+    println!("cargo:rerun-if-env-changed=BLOCKPEDIA_BLOCK_ALLOWLIST");
 
     // Check if we should use pre-built data
     if cfg!(feature = "use-prebuilt") || env::var("BLOCKPEDIA_USE_PREBUILT").is_ok() {
@@ -1669,6 +1788,13 @@ fn generate_phf_table(
                     );
                 }
 
+                // Keep the fields extras are derived from
+                for key in ["hardness", "diggable", "displayName"] {
+                    if let Some(value) = block_obj.get(key) {
+                        converted_block.insert(key.to_string(), value.clone());
+                    }
+                }
+
                 // Empty default state for now
                 converted_block.insert(
                     "default_state".to_string(),
@@ -1685,6 +1811,11 @@ fn generate_phf_table(
         anyhow::bail!("Unsupported JSON format");
     };
 
+    let block_data = match load_block_allowlist()? {
+        Some(allowlist) => retain_allowlisted(block_data, &allowlist, |(id, _)| id.as_str())?,
+        None => block_data,
+    };
+
     // Generate the static block data
     for (block_id, block_data) in &block_data {
         let block_obj = block_data
@@ -1705,6 +1836,12 @@ fn generate_phf_table(
             .and_then(|d| d.as_object())
             .unwrap_or(&empty_state);
 
+        // Check the default state against the declared properties
+        let invalid_defaults = invalid_json_default_state(properties, default_state);
+        for (property, value) in &invalid_defaults {
+            warn_invalid_default(block_id, property, value);
+        }
+
         // Generate a valid Rust identifier from block ID
         let safe_name = block_id.replace(":", "_").replace("-", "_").to_uppercase();
 
@@ -1726,6 +1863,10 @@ fn generate_phf_table(
         writeln!(file, "    properties: &[")?;
         for (prop_name, prop_values) in properties {
             if let Some(values_array) = prop_values.as_array() {
+                if values_array.is_empty() {
+                    warn_empty_property(block_id, prop_name);
+                    continue;
+                }
                 write!(file, "        (\"{}\", &[", prop_name)?;
                 for (i, value) in values_array.iter().enumerate() {
                     if i > 0 {
@@ -1741,6 +1882,12 @@ fn generate_phf_table(
         // Generate default_state array
         writeln!(file, "    default_state: &[")?;
         for (state_name, state_value) in default_state {
+            if invalid_defaults
+                .iter()
+                .any(|(property, _)| property == state_name)
+            {
+                continue;
+            }
             writeln!(
                 file,
                 "        (\"{}\", \"{}\"),",
@@ -1783,6 +1930,24 @@ fn generate_phf_table(
             write!(file, " color: None,")?;
         }
 
+        // Obtainability
+        match obtainable_from_data(block_obj.get("hardness"), block_obj.get("diggable")) {
+            Some(obtainable) => write!(file, " obtainable: Some({}),", obtainable)?,
+            None => write!(file, " obtainable: None,")?,
+        }
+
+        // Hardness
+        match hardness_from_data(block_obj.get("hardness")) {
+            Some(hardness) => write!(file, " hardness: Some({:?}),", hardness)?,
+            None => write!(file, " hardness: None,")?,
+        }
+
+        // Display name
+        match block_obj.get("displayName").and_then(|n| n.as_str()) {
+            Some(name) => write!(file, " display_name: Some({:?}),", name)?,
+            None => write!(file, " display_name: None,")?,
+        }
+
         writeln!(file, " bedrock: None,")?;
 
         writeln!(file, " }},")?;
@@ -1820,7 +1985,10 @@ fn generate_legacy_phf_table(out_dir: &str, json: &Value) -> Result<()> {
     let mut fetcher_registry = setup_fetchers();
 
     // Get list of available block IDs from JSON
-    let available_block_ids = get_block_ids_from_json(json)?;
+    let mut available_block_ids = get_block_ids_from_json(json)?;
+    if let Some(allowlist) = load_block_allowlist()? {
+        available_block_ids.retain(|id| allowlist.contains(id));
+    }
 
     // Fetch extra data from all registered fetchers
     let extra_data = fetcher_registry.fetch_all(&available_block_ids)?.clone();
@@ -1831,6 +1999,18 @@ fn generate_legacy_phf_table(out_dir: &str, json: &Value) -> Result<()> {
 
 // Generate PHF table from unified block data
 fn generate_unified_phf_table(out_dir: &str, unified_blocks: &[UnifiedBlockData]) -> Result<()> {
+    // Trim the table to the allowlist (if any) before colors and mappings are derived from it
+    let allowlist = load_block_allowlist()?;
+    let allowlisted_blocks;
+    let unified_blocks = match &allowlist {
+        Some(allowlist) => {
+            allowlisted_blocks =
+                retain_allowlisted(unified_blocks.to_vec(), allowlist, |b| b.id.as_str())?;
+            &allowlisted_blocks[..]
+        }
+        None => unified_blocks,
+    };
+
     let table_path = Path::new(out_dir).join("block_table.rs");
     let mut file = std::fs::File::create(&table_path).context("Failed to create block_table.rs")?;
 
@@ -1868,6 +2048,10 @@ fn generate_unified_phf_table(out_dir: &str, unified_blocks: &[UnifiedBlockData]
         // Generate properties array
         writeln!(file, "    properties: &[")?;
         for (prop_name, prop_values) in &block_data.properties {
+            if prop_values.is_empty() {
+                warn_empty_property(block_id, prop_name);
+                continue;
+            }
             write!(file, "        (\"{}\", &[", prop_name)?;
             for (i, value) in prop_values.iter().enumerate() {
                 if i > 0 {
@@ -1881,7 +2065,17 @@ fn generate_unified_phf_table(out_dir: &str, unified_blocks: &[UnifiedBlockData]
 
         // Generate default_state array
         writeln!(file, "    default_state: &[")?;
+        let invalid_defaults = block_data.invalid_default_state();
+        for (property, value) in &invalid_defaults {
+            warn_invalid_default(block_id, property, value);
+        }
         for (state_name, state_value) in &block_data.default_state {
+            if invalid_defaults
+                .iter()
+                .any(|(property, _)| property == state_name)
+            {
+                continue;
+            }
             writeln!(file, "        (\"{}\", \"{}\"),", state_name, state_value)?;
         }
         writeln!(file, "    ],")?;
@@ -1919,6 +2113,31 @@ fn generate_unified_phf_table(out_dir: &str, unified_blocks: &[UnifiedBlockData]
             write!(file, " color: None,")?;
         }
 
+        // Obtainability
+        match obtainable_from_data(
+            block_data.extra_properties.get("hardness"),
+            block_data.extra_properties.get("diggable"),
+        ) {
+            Some(obtainable) => write!(file, " obtainable: Some({}),", obtainable)?,
+            None => write!(file, " obtainable: None,")?,
+        }
+
+        // Hardness
+        match hardness_from_data(block_data.extra_properties.get("hardness")) {
+            Some(hardness) => write!(file, " hardness: Some({:?}),", hardness)?,
+            None => write!(file, " hardness: None,")?,
+        }
+
+        // Display name
+        match block_data
+            .extra_properties
+            .get("displayName")
+            .and_then(|n| n.as_str())
+        {
+            Some(name) => write!(file, " display_name: Some({:?}),", name)?,
+            None => write!(file, " display_name: None,")?,
+        }
+
         // Bedrock data
         if let Some(ref bedrock_id) = block_data.bedrock_id {
             writeln!(file, " bedrock: Some(crate::BedrockData {{")?;
@@ -1989,13 +2208,14 @@ fn generate_unified_phf_table(out_dir: &str, unified_blocks: &[UnifiedBlockData]
     );
 
     // Generate bedrock mappings from JSON files
-    generate_bedrock_mappings(out_dir)?;
+    generate_bedrock_mappings(out_dir, allowlist.as_ref())?;
 
     Ok(())
 }
 
 /// Generate bedrock blockstate mappings from geyser_mappings.json
-fn generate_bedrock_mappings(out_dir: &str) -> Result<()> {
+/// When an allowlist is given, only mappings for allowlisted Java blocks are emitted
+fn generate_bedrock_mappings(out_dir: &str, allowlist: Option<&HashSet<String>>) -> Result<()> {
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
     let data_dir = Path::new(&manifest_dir).join("data");
 
@@ -2034,6 +2254,7 @@ fn generate_bedrock_mappings(out_dir: &str) -> Result<()> {
 
                 // Collect mappings for B2J (we'll need to reverse them)
                 let mut b2j_map: HashMap<String, String> = HashMap::new();
+                let mut j2b_count = 0;
 
                 for mapping in mappings {
                     let java_state_obj = mapping.get("java_state").and_then(|s| s.as_object());
@@ -2042,6 +2263,9 @@ fn generate_bedrock_mappings(out_dir: &str) -> Result<()> {
                     if let (Some(java), Some(bedrock)) = (java_state_obj, bedrock_state_obj) {
                         // Format Java String
                         let java_name = java.get("Name").and_then(|n| n.as_str()).unwrap_or("");
+                        if allowlist.is_some_and(|allowlist| !allowlist.contains(java_name)) {
+                            continue;
+                        }
                         let java_props = java.get("Properties").and_then(|p| p.as_object());
                         
                         let java_state_str = if let Some(props) = java_props {
@@ -2083,7 +2307,8 @@ fn generate_bedrock_mappings(out_dir: &str) -> Result<()> {
                         };
 
                         writeln!(file, "    r#\"{}\"# => r#\"{}\"#,", java_state_str, bedrock_state_str)?;
-                        
+                        j2b_count += 1;
+
                         // Store for B2J (overwrite duplicates, last wins - or check logic)
                         b2j_map.entry(bedrock_state_str).or_insert(java_state_str);
                     }
@@ -2093,7 +2318,7 @@ fn generate_bedrock_mappings(out_dir: &str) -> Result<()> {
 
                 println!(
                     "cargo:warning=Generated {} Java->Bedrock mappings from Geyser",
-                    mappings.len()
+                    j2b_count
                 );
 
                 // Bedrock -> Java Map
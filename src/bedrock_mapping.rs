@@ -20,6 +20,16 @@ impl BedrockBlockStateMapper {
         BEDROCK_B2J_MAP.get(&normalized).copied()
     }
 
+    /// Iterate over every (Java blockstate, Bedrock blockstate) pair in the mapping table
+    ///
+    /// Keys are already normalized, so each one can be passed straight back to
+    /// [`Self::java_to_bedrock`]. Iteration order is unspecified.
+    pub fn all_mappings() -> impl Iterator<Item = (&'static str, &'static str)> {
+        BEDROCK_J2B_MAP
+            .entries()
+            .map(|(java, bedrock)| (*java, *bedrock))
+    }
+
     /// Normalize a blockstate string by sorting properties alphabetically
     /// This ensures consistent matching regardless of property order
     fn normalize_blockstate(blockstate: &str) -> String {
@@ -3,12 +3,11 @@ use crossterm::event::{self, Event as CEvent, KeyCode};
 use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::Line;
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{
     BarChart, Block, Borders, Cell, List, ListItem, Paragraph, Row, Table, Tabs,
 };
 use ratatui::Terminal;
-use std::collections::HashMap;
 use std::{error::Error, io};
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -185,7 +184,20 @@ fn render_blocks_tab(f: &mut ratatui::Frame, app: &App, area: Rect) {
                 format!(" {}", indicators.join(""))
             };
 
-            ListItem::new(format!("{}{}", block.id(), indicator_text)).style(style)
+            // Colored blocks get an inline swatch
+            let swatch = match block.extras.color {
+                Some(color) => Span::styled(
+                    "  ",
+                    Style::default().bg(color.to_extended().to_ratatui_color()),
+                ),
+                None => Span::raw("  "),
+            };
+
+            ListItem::new(Line::from(vec![
+                swatch,
+                Span::raw(format!(" {}{}", block.id(), indicator_text)),
+            ]))
+            .style(style)
         })
         .collect();
 
@@ -199,47 +211,65 @@ fn render_blocks_tab(f: &mut ratatui::Frame, app: &App, area: Rect) {
     f.render_widget(blocks_list, chunks[0]);
 
     // Block details
-    if let Some(selected_block) = app.get_selected_block() {
-        let mut details = vec![format!("ID: {}", selected_block.id()), String::new()];
+    if let Some(info) = app
+        .get_selected_block()
+        .and_then(|block| blockpedia::block_info(block.id))
+    {
+        let mut details = vec![
+            format!("ID: {}", info.id),
+            format!("Name: {}", info.display_name),
+            format!("Category: {:?}", info.category),
+            format!("Obtainability: {:?}", info.obtainability),
+            String::new(),
+        ];
 
         // Properties
-        if selected_block.properties.is_empty() {
+        if info.properties.is_empty() {
             details.push("Properties: None".to_string());
         } else {
             details.push("Properties:".to_string());
-            for (prop, values) in selected_block.properties {
+            for (prop, values) in info.properties {
                 details.push(format!("  {}: {:?}", prop, values));
             }
         }
 
         details.push(String::new());
+        details.push(format!("Default State: {}", info.default_state));
+        if let Some(hardness) = info.hardness {
+            details.push(format!("Hardness: {}", hardness));
+        }
 
-        // Default state
-        if selected_block.default_state.is_empty() {
-            details.push("Default State: None".to_string());
-        } else {
-            details.push("Default State:".to_string());
-            for (key, value) in selected_block.default_state {
-                details.push(format!("  {}: {}", key, value));
-            }
+        let mut flags = Vec::new();
+        if info.transparent {
+            flags.push("transparent");
+        }
+        if info.light_source {
+            flags.push("light source");
+        }
+        if info.interactive {
+            flags.push("interactive");
+        }
+        if !flags.is_empty() {
+            details.push(format!("Flags: {}", flags.join(", ")));
         }
 
-        // Fetcher data
-        if selected_block.extras.mock_data.is_some() || selected_block.extras.color.is_some() {
+        if let Some(color) = &info.color {
             details.push(String::new());
-            details.push("Extra Data:".to_string());
-
-            if let Some(mock_val) = selected_block.extras.mock_data {
-                details.push(format!("  Mock Data: {}", mock_val));
+            details.push("Color:".to_string());
+            details.push(format!("  RGB Color: {:?}", color.color.rgb));
+            details.push(format!("  Hex: {}", color.hex));
+            details.push(format!(
+                "  Oklab: [{:.2}, {:.2}, {:.2}]",
+                color.color.oklab[0], color.color.oklab[1], color.color.oklab[2]
+            ));
+            if let Some(dye) = color.nearest_dye {
+                details.push(format!("  Nearest Dye: {}", dye));
             }
+        }
 
-            if let Some(color) = selected_block.extras.color {
-                details.push(format!("  RGB Color: {:?}", color.rgb));
-                details.push(format!(
-                    "  Oklab: [{:.2}, {:.2}, {:.2}]",
-                    color.oklab[0], color.oklab[1], color.oklab[2]
-                ));
-            }
+        if !info.variants.is_empty() {
+            details.push(String::new());
+            details.push(format!("Variants: {}", info.variants.join(", ")));
         }
 
         let details_text: Vec<Line> = details.iter().map(|s| Line::from(s.clone())).collect();
@@ -364,7 +394,7 @@ fn render_query_tab(f: &mut ratatui::Frame, app: &App, area: Rect) {
 }
 
 fn render_properties_tab(f: &mut ratatui::Frame, _app: &App, area: Rect) {
-    let all_properties = get_all_properties();
+    let all_properties = get_all_properties_cached();
 
     let rows: Vec<Row> = all_properties
         .iter()
@@ -423,7 +453,7 @@ fn render_statistics_tab(f: &mut ratatui::Frame, _app: &App, area: Rect) {
     f.render_widget(stats_paragraph, chunks[0]);
 
     // Property frequency chart
-    let all_props = get_all_properties();
+    let all_props = get_all_properties_cached();
     let property_counts: Vec<_> = all_props
         .iter()
         .map(|(prop, values)| (prop.as_str(), values.len() as u64))
@@ -518,24 +548,6 @@ fn handle_statistics_input(_key: crossterm::event::KeyEvent, _app: &mut App) {
     // Statistics tab is read-only for now
 }
 
-fn get_all_properties() -> HashMap<String, Vec<String>> {
-    let mut all_props = HashMap::new();
-    for block in BLOCKS.values() {
-        for (prop, values) in block.properties {
-            let entry = all_props.entry(prop.to_string()).or_insert_with(Vec::new);
-            for value in values.iter() {
-                if !entry.contains(&value.to_string()) {
-                    entry.push(value.to_string());
-                }
-            }
-        }
-    }
-    for values in all_props.values_mut() {
-        values.sort();
-    }
-    all_props
-}
-
 #[derive(Debug, Clone, Copy)]
 enum Tab {
     Blocks = 0,
@@ -920,8 +932,8 @@ impl App {
         let mut avg_red = 0.0;
         let mut avg_green = 0.0;
         let mut avg_blue = 0.0;
-        let mut brightest = (String::new(), 0u32);
-        let mut darkest = (String::new(), 255u32 * 3);
+        let mut brightest = (String::new(), f32::MIN);
+        let mut darkest = (String::new(), f32::MAX);
 
         for block in &colored_blocks {
             if let Some(color) = &block.extras.color {
@@ -929,7 +941,7 @@ impl App {
                 avg_green += color.rgb[1] as f64;
                 avg_blue += color.rgb[2] as f64;
 
-                let brightness = color.rgb[0] as u32 + color.rgb[1] as u32 + color.rgb[2] as u32;
+                let brightness = color.to_extended().perceived_brightness();
                 if brightness > brightest.1 {
                     brightest = (block.id().to_string(), brightness);
                 }
@@ -957,10 +969,13 @@ impl App {
             ),
             "".to_string(),
             format!(
-                "Brightest block: {} (brightness: {})",
+                "Brightest block: {} (brightness: {:.2})",
                 brightest.0, brightest.1
             ),
-            format!("Darkest block: {} (brightness: {})", darkest.0, darkest.1),
+            format!(
+                "Darkest block: {} (brightness: {:.2})",
+                darkest.0, darkest.1
+            ),
         ];
     }
 
@@ -3,7 +3,7 @@ use crossterm::event::{self, Event as CEvent, KeyCode};
 use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::Line;
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Tabs};
 use ratatui::Terminal;
 use std::{error::Error, io};
@@ -276,7 +276,20 @@ fn render_results_tab(f: &mut ratatui::Frame, app: &App, area: Rect) {
                 format!(" {}", indicators.join(""))
             };
 
-            ListItem::new(format!("{}{}", block.id(), indicator_text)).style(style)
+            // Colored blocks get an inline swatch
+            let swatch = match block.extras.color {
+                Some(color) => Span::styled(
+                    "  ",
+                    Style::default().bg(color.to_extended().to_ratatui_color()),
+                ),
+                None => Span::raw("  "),
+            };
+
+            ListItem::new(Line::from(vec![
+                swatch,
+                Span::raw(format!(" {}{}", block.id(), indicator_text)),
+            ]))
+            .style(style)
         })
         .collect();
 
@@ -76,7 +76,13 @@ fn main() {
             "#{:02X}{:02X}{:02X}",
             color.rgb[0], color.rgb[1], color.rgb[2]
         );
-        println!("    • {} → {} (distance: {:.1})", block.id(), hex, distance);
+        println!(
+            "    • {} {} → {} (distance: {:.1})",
+            color.to_extended().to_ansi_truecolor(),
+            block.id(),
+            hex,
+            distance
+        );
     }
 
     // Test Color Analysis
@@ -84,8 +90,8 @@ fn main() {
     let mut avg_red = 0.0;
     let mut avg_green = 0.0;
     let mut avg_blue = 0.0;
-    let mut brightest = (String::new(), 0u32);
-    let mut darkest = (String::new(), 255u32 * 3);
+    let mut brightest = (String::new(), f32::MIN);
+    let mut darkest = (String::new(), f32::MAX);
 
     for block in &blocks_with_color {
         if let Some(color) = &block.extras.color {
@@ -93,7 +99,7 @@ fn main() {
             avg_green += color.rgb[1] as f64;
             avg_blue += color.rgb[2] as f64;
 
-            let brightness = color.rgb[0] as u32 + color.rgb[1] as u32 + color.rgb[2] as u32;
+            let brightness = color.to_extended().perceived_brightness();
             if brightness > brightest.1 {
                 brightest = (block.id().to_string(), brightness);
             }
@@ -117,10 +123,13 @@ fn main() {
         avg_red as u8, avg_green as u8, avg_blue as u8
     );
     println!(
-        "  Brightest block: {} (brightness: {})",
+        "  Brightest block: {} (brightness: {:.2})",
         brightest.0, brightest.1
     );
-    println!("  Darkest block: {} (brightness: {})", darkest.0, darkest.1);
+    println!(
+        "  Darkest block: {} (brightness: {:.2})",
+        darkest.0, darkest.1
+    );
 
     println!("\n✅ All Colors CLI features are working correctly!");
     println!("🎨 Ready to showcase our amazing color system in the interactive CLI!");
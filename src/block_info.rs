@@ -0,0 +1,89 @@
+use crate::color::{nearest_wool_block, ExtendedColorData};
+use crate::query_builder::BlockQuery;
+use crate::transforms::{BlockShape, BlockTransforms};
+use crate::{get_block, BlockCategory, BlockState, Obtainability, BLOCKS};
+
+/// Everything blockpedia knows about a single block, gathered in one call
+///
+/// Built by [`block_info`].
+#[derive(Debug, Clone)]
+pub struct BlockInfo {
+    pub id: &'static str,
+    pub display_name: String,
+    pub category: BlockCategory,
+    /// Property names with their allowed values
+    pub properties: &'static [(&'static str, &'static [&'static str])],
+    /// Default state, with the first-allowed-value fallback of [`BlockState::from_default`]
+    pub default_state: BlockState,
+    pub color: Option<BlockColorInfo>,
+    pub transparent: bool,
+    /// Mining hardness, negative for unbreakable blocks (`None` when the data lacks it)
+    pub hardness: Option<f32>,
+    pub light_source: bool,
+    pub interactive: bool,
+    pub obtainability: Obtainability,
+    /// IDs of other shapes of the same material (stairs, slabs, walls, ...)
+    pub variants: Vec<&'static str>,
+}
+
+/// Color details for a [`BlockInfo`]
+#[derive(Debug, Clone)]
+pub struct BlockColorInfo {
+    pub color: ExtendedColorData,
+    /// Hex string such as `#A2834F`
+    pub hex: String,
+    /// Dye color whose wool is closest to this color (e.g. `"brown"`)
+    pub nearest_dye: Option<&'static str>,
+}
+
+/// Gather the details of a block into a [`BlockInfo`], or `None` for an unknown ID
+pub fn block_info(id: &str) -> Option<BlockInfo> {
+    let facts = get_block(id)?;
+    let default_state = BlockState::from_default(facts).ok()?;
+
+    let color = facts.extras.color.map(|color| {
+        let color = color.to_extended();
+        BlockColorInfo {
+            color,
+            hex: color.hex_string(),
+            nearest_dye: nearest_wool_block(color).and_then(|wool| {
+                wool.id
+                    .strip_prefix("minecraft:")
+                    .and_then(|name| name.strip_suffix("_wool"))
+            }),
+        }
+    });
+
+    let shapes = [
+        BlockShape::Stairs,
+        BlockShape::Slab,
+        BlockShape::Wall,
+        BlockShape::Fence,
+        BlockShape::FenceGate,
+        BlockShape::Door,
+        BlockShape::Trapdoor,
+        BlockShape::Button,
+        BlockShape::PressurePlate,
+    ];
+    let variants = shapes
+        .into_iter()
+        .filter_map(|shape| BlockTransforms::get_shape_variant(&default_state, shape).ok())
+        .filter_map(|variant| BLOCKS.get(variant.id()).map(|block| block.id))
+        .filter(|variant_id| *variant_id != facts.id)
+        .collect();
+
+    Some(BlockInfo {
+        id: facts.id,
+        display_name: facts.display_name(),
+        category: facts.category(),
+        properties: facts.properties,
+        default_state,
+        color,
+        transparent: facts.transparent,
+        hardness: facts.extras.hardness,
+        light_source: BlockQuery::is_light_source(facts),
+        interactive: facts.is_interactive(),
+        obtainability: facts.obtainability(),
+        variants,
+    })
+}
@@ -1,6 +1,6 @@
 use super::{palettes::GradientMethod, ExtendedColorData};
-use crate::BlockFacts;
 use crate::BLOCKS;
+use crate::{BlockCategory, BlockFacts};
 /// Generate palettes of actual Minecraft blocks based on color relationships
 pub struct BlockPaletteGenerator;
 
@@ -33,6 +33,7 @@ pub enum PaletteTheme {
     Natural,
     Architectural,
     Seasonal,
+    Custom,
 }
 
 /// Role of a block in the palette
@@ -486,6 +487,55 @@ impl BlockPaletteGenerator {
         })
     }
 
+    /// Generate a palette of `n` blocks with evenly spaced hues
+    ///
+    /// Each hue from `PaletteGenerator::generate_distinct_hues` is mapped to the closest
+    /// block that hasn't already been picked, so every entry is a different block. Useful
+    /// for assigning blocks to categories, e.g. when building a legend.
+    pub fn generate_distinct_hue_palette(n: usize) -> Option<BlockPalette> {
+        let hues = super::palettes::PaletteGenerator::generate_distinct_hues(n);
+
+        let mut blocks: Vec<BlockRecommendation> = Vec::new();
+        for target_color in hues {
+            let closest = BLOCKS
+                .values()
+                .filter(|block| !blocks.iter().any(|picked| picked.block.id == block.id))
+                .filter_map(|block| {
+                    block.extras.color.map(|color| {
+                        let color = color.to_extended();
+                        (*block, color, color.distance_oklab(&target_color))
+                    })
+                })
+                .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal));
+
+            if let Some((block, color, _)) = closest {
+                let role = BlockRole::Primary;
+                let usage_notes = Self::generate_usage_notes(block, &role);
+
+                blocks.push(BlockRecommendation {
+                    block,
+                    color,
+                    role,
+                    usage_notes,
+                });
+            }
+        }
+
+        if blocks.is_empty() {
+            return None;
+        }
+
+        Some(BlockPalette {
+            name: format!("{} Distinct Hues", blocks.len()),
+            description: format!(
+                "{} visually distinct blocks spaced evenly around the color wheel",
+                blocks.len()
+            ),
+            blocks,
+            theme: PaletteTheme::Custom,
+        })
+    }
+
     /// Generate natural palettes based on Minecraft biomes/themes
     pub fn generate_natural_palette(theme: &str) -> Option<BlockPalette> {
         Self::generate_natural_palette_filtered(theme, &BlockFilter::default())
@@ -546,23 +596,21 @@ impl BlockPaletteGenerator {
 
     /// Generate usage notes for a block in a specific role
     fn generate_usage_notes(block: &BlockFacts, role: &BlockRole) -> String {
-        let block_type = Self::categorize_block(block);
-
-        match (role, block_type.as_str()) {
-            (BlockRole::Primary, "stone") => {
+        match (role, block.category()) {
+            (BlockRole::Primary, BlockCategory::Stone) => {
                 "Excellent for foundations, walls, and main structures".to_string()
             }
-            (BlockRole::Primary, "wood") => {
+            (BlockRole::Primary, BlockCategory::Wood) => {
                 "Great for frames, floors, and warm architectural elements".to_string()
             }
-            (BlockRole::Primary, "concrete") => {
+            (BlockRole::Primary, BlockCategory::Concrete | BlockCategory::Terracotta) => {
                 "Perfect for modern builds and large surfaces".to_string()
             }
 
-            (BlockRole::Secondary, "stone") => {
+            (BlockRole::Secondary, BlockCategory::Stone) => {
                 "Use for detailing, trim, and structural accents".to_string()
             }
-            (BlockRole::Secondary, "wood") => {
+            (BlockRole::Secondary, BlockCategory::Wood) => {
                 "Ideal for stairs, slabs, and secondary features".to_string()
             }
             (BlockRole::Secondary, _) => {
@@ -583,44 +631,9 @@ impl BlockPaletteGenerator {
         }
     }
 
-    /// Categorize a block by its material type
-    fn categorize_block(block: &BlockFacts) -> String {
-        let id = block.id().to_lowercase();
-
-        if id.contains("stone") || id.contains("cobblestone") || id.contains("brick") {
-            "stone".to_string()
-        } else if id.contains("wood") || id.contains("plank") || id.contains("log") {
-            "wood".to_string()
-        } else if id.contains("concrete") || id.contains("terracotta") {
-            "concrete".to_string()
-        } else if id.contains("wool") || id.contains("carpet") {
-            "fabric".to_string()
-        } else if id.contains("glass") {
-            "glass".to_string()
-        } else if id.contains("metal") || id.contains("iron") || id.contains("gold") {
-            "metal".to_string()
-        } else {
-            "other".to_string()
-        }
-    }
-
     /// Get a friendly display name for a block
     fn block_display_name(block: &BlockFacts) -> String {
-        block
-            .id()
-            .strip_prefix("minecraft:")
-            .unwrap_or(block.id())
-            .replace('_', " ")
-            .split_whitespace()
-            .map(|word| {
-                let mut chars = word.chars();
-                match chars.next() {
-                    None => String::new(),
-                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-                }
-            })
-            .collect::<Vec<_>>()
-            .join(" ")
+        block.display_name()
     }
 
     // Natural palette generators
@@ -888,12 +901,7 @@ impl BlockPaletteGenerator {
                     continue;
                 }
 
-                let role = match i {
-                    0 => BlockRole::Primary,
-                    1 => BlockRole::Secondary,
-                    _ if i == block_ids.len() - 1 => BlockRole::Accent,
-                    _ => BlockRole::Transition,
-                };
+                let role = Self::role_for_position(i, block_ids.len());
 
                 let color = block
                     .extras
@@ -924,6 +932,16 @@ impl BlockPaletteGenerator {
         })
     }
 
+    /// Assign a palette role based on a block's position in an ordered list
+    fn role_for_position(index: usize, len: usize) -> BlockRole {
+        match index {
+            0 => BlockRole::Primary,
+            1 => BlockRole::Secondary,
+            _ if index == len - 1 => BlockRole::Accent,
+            _ => BlockRole::Transition,
+        }
+    }
+
     /// Find blocks by color similarity for custom palettes
     pub fn find_blocks_by_color_range(
         target_color: ExtendedColorData,
@@ -960,6 +978,43 @@ impl BlockPaletteGenerator {
     }
 }
 
+/// Build a palette from an explicit, ordered list of block IDs
+///
+/// Roles are assigned by position: the first block is primary, the second secondary,
+/// the last an accent and anything in between a transition. Blocks without color data
+/// fall back to a neutral gray. Fails on the first ID that isn't a known block.
+pub fn palette_from_block_ids(block_ids: &[&str], name: &str) -> crate::Result<BlockPalette> {
+    let mut blocks = Vec::with_capacity(block_ids.len());
+
+    for (i, block_id) in block_ids.iter().enumerate() {
+        let block = *BLOCKS
+            .get(*block_id)
+            .ok_or_else(|| crate::BlockpediaError::block_not_found(block_id))?;
+
+        let role = BlockPaletteGenerator::role_for_position(i, block_ids.len());
+        let color = block
+            .extras
+            .color
+            .map(|c| c.to_extended())
+            .unwrap_or_else(|| ExtendedColorData::from_rgb(128, 128, 128));
+        let usage_notes = BlockPaletteGenerator::generate_usage_notes(block, &role);
+
+        blocks.push(BlockRecommendation {
+            block,
+            color,
+            role,
+            usage_notes,
+        });
+    }
+
+    Ok(BlockPalette {
+        name: name.to_string(),
+        description: format!("Custom palette of {} blocks", blocks.len()),
+        blocks,
+        theme: PaletteTheme::Custom,
+    })
+}
+
 impl BlockPalette {
     /// Export palette as a text list for easy copying
     pub fn to_text_list(&self) -> String {
@@ -1000,18 +1055,6 @@ impl BlockPalette {
 
     /// Format block ID into a readable name
     fn format_block_name(id: &str) -> String {
-        id.strip_prefix("minecraft:")
-            .unwrap_or(id)
-            .replace('_', " ")
-            .split_whitespace()
-            .map(|word| {
-                let mut chars = word.chars();
-                match chars.next() {
-                    None => String::new(),
-                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-                }
-            })
-            .collect::<Vec<_>>()
-            .join(" ")
+        crate::display_name(id)
     }
 }
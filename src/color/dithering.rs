@@ -0,0 +1,98 @@
+use super::ExtendedColorData;
+use crate::BlockFacts;
+use anyhow::{bail, Result};
+use image::DynamicImage;
+
+/// Convert an image into a grid of block IDs using Floyd–Steinberg error diffusion
+///
+/// Each pixel is matched to the nearest palette block in Oklab space and the
+/// quantization error is pushed onto the neighbouring pixels, so smooth gradients
+/// come out as a blend of blocks instead of hard bands. Palette blocks without color
+/// data are ignored. The grid is indexed as `grid[y][x]`.
+pub fn dither_image_to_blocks(
+    img: &DynamicImage,
+    palette: &[&'static BlockFacts],
+) -> Result<Vec<Vec<&'static str>>> {
+    let candidates: Vec<(&'static str, [f32; 3])> = palette
+        .iter()
+        .filter_map(|block| {
+            block
+                .extras
+                .color
+                .map(|color| (block.id, color.to_extended().oklab))
+        })
+        .collect();
+
+    if candidates.is_empty() {
+        bail!("Palette contains no blocks with color data");
+    }
+
+    let rgb_img = img.to_rgb8();
+    let (width, height) = rgb_img.dimensions();
+    let (width, height) = (width as usize, height as usize);
+
+    // Working buffer of Oklab values that accumulates diffused error
+    let mut working: Vec<[f32; 3]> = rgb_img
+        .pixels()
+        .map(|pixel| ExtendedColorData::from_rgb(pixel[0], pixel[1], pixel[2]).oklab)
+        .collect();
+
+    let mut grid = Vec::with_capacity(height);
+    for y in 0..height {
+        let mut row = Vec::with_capacity(width);
+        for x in 0..width {
+            let current = working[y * width + x];
+            let (block_id, chosen) = nearest_candidate(&candidates, current);
+            row.push(block_id);
+
+            let error = [
+                current[0] - chosen[0],
+                current[1] - chosen[1],
+                current[2] - chosen[2],
+            ];
+
+            let mut diffuse = |dx: isize, dy: usize, weight: f32| {
+                let nx = x as isize + dx;
+                let ny = y + dy;
+                if nx < 0 || nx as usize >= width || ny >= height {
+                    return;
+                }
+                let target = &mut working[ny * width + nx as usize];
+                for channel in 0..3 {
+                    target[channel] += error[channel] * weight;
+                }
+            };
+
+            diffuse(1, 0, 7.0 / 16.0);
+            diffuse(-1, 1, 3.0 / 16.0);
+            diffuse(0, 1, 5.0 / 16.0);
+            diffuse(1, 1, 1.0 / 16.0);
+        }
+        grid.push(row);
+    }
+
+    Ok(grid)
+}
+
+/// Find the palette entry closest to an Oklab value
+fn nearest_candidate(
+    candidates: &[(&'static str, [f32; 3])],
+    target: [f32; 3],
+) -> (&'static str, [f32; 3]) {
+    let distance = |oklab: &[f32; 3]| {
+        let dl = oklab[0] - target[0];
+        let da = oklab[1] - target[1];
+        let db = oklab[2] - target[2];
+        dl * dl + da * da + db * db
+    };
+
+    candidates
+        .iter()
+        .min_by(|a, b| {
+            distance(&a.1)
+                .partial_cmp(&distance(&b.1))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .copied()
+        .expect("candidates checked to be non-empty")
+}
@@ -4,12 +4,16 @@ use palette::{IntoColor, Lab, Oklch, Srgb};
 use std::path::Path;
 
 pub mod block_palettes;
+pub mod dithering;
 pub mod extraction;
 pub mod palettes;
 pub mod similarity;
 pub mod spaces;
 pub mod texture_mapping;
 
+pub use block_palettes::palette_from_block_ids;
+pub use dithering::dither_image_to_blocks;
+
 /// Extended color data structure supporting multiple color spaces
 #[derive(Debug, Clone, Copy)]
 pub struct ExtendedColorData {
@@ -21,6 +25,17 @@ pub struct ExtendedColorData {
     pub hex: u32,
 }
 
+/// Rough warm/cool classification of a color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorTemperature {
+    /// Reds, oranges and yellows
+    Warm,
+    /// Grays, whites, blacks and other low-chroma colors
+    Neutral,
+    /// Greens, blues and purples
+    Cool,
+}
+
 impl ExtendedColorData {
     /// Create ExtendedColorData from RGB values
     pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
@@ -78,6 +93,63 @@ impl ExtendedColorData {
         let db = (self.rgb[2] as f32) - (other.rgb[2] as f32);
         (dr * dr + dg * dg + db * db).sqrt()
     }
+
+    /// Perceived brightness (0.0-1.0) using the Rec. 601 luma weights
+    ///
+    /// Green contributes far more than blue, so this tracks what the eye sees much better
+    /// than summing the RGB channels.
+    pub fn perceived_brightness(&self) -> f32 {
+        (0.299 * self.rgb[0] as f32 + 0.587 * self.rgb[1] as f32 + 0.114 * self.rgb[2] as f32)
+            / 255.0
+    }
+
+    /// Render the color as a two-cell terminal swatch
+    ///
+    /// Uses a 24-bit ANSI background escape followed by a reset, so the swatch can be
+    /// printed inline before other text.
+    pub fn to_ansi_truecolor(&self) -> String {
+        format!(
+            "\x1b[48;2;{};{};{}m  \x1b[0m",
+            self.rgb[0], self.rgb[1], self.rgb[2]
+        )
+    }
+
+    /// Convert to a ratatui color for TUI rendering
+    #[cfg(feature = "tui")]
+    pub fn to_ratatui_color(&self) -> ratatui::style::Color {
+        ratatui::style::Color::Rgb(self.rgb[0], self.rgb[1], self.rgb[2])
+    }
+
+    /// Classify the color as warm, neutral or cool
+    ///
+    /// Colors with little chroma are neutral regardless of hue; the rest are split on
+    /// the HSL hue wheel, with reds through yellows (and red-leaning magentas) counted
+    /// as warm.
+    pub fn temperature(&self) -> ColorTemperature {
+        if self.oklch[1] < 0.04 {
+            return ColorTemperature::Neutral;
+        }
+        let hue = self.hsl[0];
+        if !(75.0..330.0).contains(&hue) {
+            ColorTemperature::Warm
+        } else {
+            ColorTemperature::Cool
+        }
+    }
+
+    /// Blend towards another color in linear light
+    ///
+    /// Channels are decoded from sRGB, interpolated, and re-encoded, which avoids the
+    /// dark, muddy midpoints produced by interpolating the gamma-encoded values directly.
+    pub fn lerp_gamma_correct(&self, other: &ExtendedColorData, t: f32) -> ExtendedColorData {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |i: usize| {
+            let start = srgb_to_linear(self.rgb[i]);
+            let end = srgb_to_linear(other.rgb[i]);
+            linear_to_srgb(start + (end - start) * t)
+        };
+        ExtendedColorData::from_rgb(channel(0), channel(1), channel(2))
+    }
 }
 
 /// Extract dominant color from an image
@@ -125,6 +197,65 @@ pub fn extract_dominant_color_from_image(img: &DynamicImage) -> Result<ExtendedC
     Ok(ExtendedColorData::from_rgb(avg_r, avg_g, avg_b))
 }
 
+/// The sixteen dye colors, in the order Minecraft lists them
+const DYE_COLORS: [&str; 16] = [
+    "white",
+    "orange",
+    "magenta",
+    "light_blue",
+    "yellow",
+    "lime",
+    "pink",
+    "gray",
+    "light_gray",
+    "cyan",
+    "purple",
+    "blue",
+    "brown",
+    "green",
+    "red",
+    "black",
+];
+
+/// Find the wool block closest in color to the target
+pub fn nearest_wool_block(target: ExtendedColorData) -> Option<&'static crate::BlockFacts> {
+    nearest_dyed_block(target, "wool", None)
+}
+
+/// Find the concrete block closest in color to the target
+pub fn nearest_concrete_block(target: ExtendedColorData) -> Option<&'static crate::BlockFacts> {
+    nearest_dyed_block(target, "concrete", None)
+}
+
+/// Find the terracotta block closest in color to the target
+///
+/// Plain (undyed) terracotta is included; glazed terracotta is not, since its
+/// patterned texture doesn't read as a flat color.
+pub fn nearest_terracotta_block(target: ExtendedColorData) -> Option<&'static crate::BlockFacts> {
+    nearest_dyed_block(target, "terracotta", Some("minecraft:terracotta"))
+}
+
+/// Search the dyed variants of a block family (plus an optional undyed base) by Oklab distance
+fn nearest_dyed_block(
+    target: ExtendedColorData,
+    suffix: &str,
+    base: Option<&str>,
+) -> Option<&'static crate::BlockFacts> {
+    DYE_COLORS
+        .iter()
+        .map(|dye| format!("minecraft:{}_{}", dye, suffix))
+        .chain(base.map(str::to_string))
+        .filter_map(|id| crate::BLOCKS.get(id.as_str()).copied())
+        .filter_map(|block| {
+            block
+                .extras
+                .color
+                .map(|color| (block, color.to_extended().distance_oklab(&target)))
+        })
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(block, _)| block)
+}
+
 /// Simple RGB to HSL conversion
 fn rgb_to_hsl(r: u8, g: u8, b: u8) -> [f32; 3] {
     let r = r as f32 / 255.0;
@@ -162,6 +293,27 @@ fn rgb_to_hsl(r: u8, g: u8, b: u8) -> [f32; 3] {
     [h * 360.0, s, l]
 }
 
+/// Decode an sRGB channel into linear light (0.0-1.0)
+fn srgb_to_linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode a linear light value back into an sRGB channel
+fn linear_to_srgb(value: f32) -> u8 {
+    let v = value.clamp(0.0, 1.0);
+    let c = if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
 /// Simple RGB to Oklab conversion (matching existing build script)
 fn rgb_to_oklab_simple(rgb: [u8; 3]) -> [f32; 3] {
     let r = rgb[0] as f32 / 255.0;
@@ -322,6 +322,23 @@ impl PaletteGenerator {
         palette
     }
 
+    /// Generate `n` categorical colors spaced evenly around the hue wheel
+    ///
+    /// Saturation and lightness are fixed so the colors differ only in hue, which suits
+    /// legends and other categorical color assignment rather than gradients.
+    pub fn generate_distinct_hues(n: usize) -> Vec<ExtendedColorData> {
+        const SATURATION: f32 = 0.75;
+        const LIGHTNESS: f32 = 0.5;
+
+        (0..n)
+            .map(|i| {
+                let hue = i as f32 * 360.0 / n as f32;
+                let rgb = hsl_to_rgb(hue, SATURATION, LIGHTNESS);
+                ExtendedColorData::from_rgb(rgb[0], rgb[1], rgb[2])
+            })
+            .collect()
+    }
+
     /// Export palette to various formats
     pub fn export_palette_css(palette: &[ExtendedColorData]) -> String {
         let mut css = String::from(":root {\n");
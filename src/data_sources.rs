@@ -15,6 +15,19 @@ pub struct UnifiedBlockData {
     pub bedrock_default_state: Option<HashMap<String, String>>,
 }
 
+impl UnifiedBlockData {
+    /// Find default_state entries that don't fit the declared properties
+    ///
+    /// Returns `(property, value)` pairs, sorted by property, for entries naming an
+    /// unknown property or a value outside that property's allowed set. Such entries would
+    /// produce an invalid default state, so the build drops them with a warning.
+    pub fn invalid_default_state(&self) -> Vec<(&str, &str)> {
+        invalid_default_state(&self.properties, &self.default_state)
+    }
+}
+
+include!("default_state_check.rs");
+
 /// Trait for different data source adapters
 pub trait DataSourceAdapter {
     fn name(&self) -> &'static str;
@@ -97,6 +110,12 @@ impl DataSourceAdapter for PrismarineAdapter {
             if let Some(resistance) = block_obj.get("resistance") {
                 extra_properties.insert("resistance".to_string(), resistance.clone());
             }
+            if let Some(diggable) = block_obj.get("diggable") {
+                extra_properties.insert("diggable".to_string(), diggable.clone());
+            }
+            if let Some(display_name) = block_obj.get("displayName") {
+                extra_properties.insert("displayName".to_string(), display_name.clone());
+            }
 
             unified_blocks.push(UnifiedBlockData {
                 id,
@@ -0,0 +1,52 @@
+// Shared by build.rs and `data_sources` through `include!`, so the check the tests cover
+// is the one that filters the generated table. Keep it free of `use` items.
+
+/// Find `(property, value)` default_state pairs naming an unknown property or a value
+/// outside that property's allowed set, sorted by property
+pub fn invalid_default_state<'a>(
+    properties: &std::collections::HashMap<String, Vec<String>>,
+    default_state: &'a std::collections::HashMap<String, String>,
+) -> Vec<(&'a str, &'a str)> {
+    let mut invalid: Vec<(&str, &str)> = default_state
+        .iter()
+        .filter(|(property, value)| {
+            !properties
+                .get(*property)
+                .is_some_and(|allowed| allowed.contains(value))
+        })
+        .map(|(property, value)| (property.as_str(), value.as_str()))
+        .collect();
+    invalid.sort();
+    invalid
+}
+
+/// [`invalid_default_state`] for the raw `properties`/`default_state` objects of the
+/// legacy JSON format, where non-string default values are read as `""`
+pub fn invalid_json_default_state(
+    properties: &serde_json::Map<String, serde_json::Value>,
+    default_state: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<(String, String)> {
+    let properties: std::collections::HashMap<String, Vec<String>> = properties
+        .iter()
+        .map(|(name, values)| {
+            let values = values
+                .as_array()
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            (name.clone(), values)
+        })
+        .collect();
+    let default_state: std::collections::HashMap<String, String> = default_state
+        .iter()
+        .map(|(name, value)| (name.clone(), value.as_str().unwrap_or("").to_string()))
+        .collect();
+    invalid_default_state(&properties, &default_state)
+        .into_iter()
+        .map(|(property, value)| (property.to_string(), value.to_string()))
+        .collect()
+}
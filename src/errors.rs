@@ -50,7 +50,14 @@ pub enum PropertyError {
 #[derive(Debug, Clone, PartialEq)]
 pub enum StateError {
     /// BlockState string parsing failed
-    ParseFailed { input: String, reason: String },
+    ParseFailed {
+        input: String,
+        reason: String,
+        /// Byte offset into `input` where the problem was detected
+        position: Option<usize>,
+        /// The fragment of `input` that could not be parsed
+        token: Option<String>,
+    },
     /// BlockState validation failed
     ValidationFailed { state: String, errors: Vec<String> },
     /// Attempting to modify immutable state
@@ -197,8 +204,24 @@ impl fmt::Display for PropertyError {
 impl fmt::Display for StateError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            StateError::ParseFailed { input, reason } => {
-                write!(f, "Failed to parse BlockState '{}': {}", input, reason)
+            StateError::ParseFailed {
+                input,
+                reason,
+                position,
+                token,
+            } => {
+                write!(f, "Failed to parse BlockState '{}': {}", input, reason)?;
+                if let Some(token) = token {
+                    write!(f, " (at '{}')", token)?;
+                }
+                if let Some(position) = position {
+                    // Point at the offending character, rustc-style
+                    let column = input
+                        .get(..*position)
+                        .map_or(*position, |prefix| prefix.chars().count());
+                    write!(f, "\n  {}\n  {}^", input, " ".repeat(column))?;
+                }
+                Ok(())
             }
             StateError::ValidationFailed { state, errors } => {
                 write!(
@@ -365,6 +388,18 @@ impl BlockpediaError {
         BlockpediaError::State(StateError::ParseFailed {
             input: input.to_string(),
             reason: reason.to_string(),
+            position: None,
+            token: None,
+        })
+    }
+
+    /// Like [`BlockpediaError::parse_failed`], but records the byte offset and offending token
+    pub fn parse_failed_at(input: &str, reason: &str, position: usize, token: &str) -> Self {
+        BlockpediaError::State(StateError::ParseFailed {
+            input: input.to_string(),
+            reason: reason.to_string(),
+            position: Some(position),
+            token: Some(token.to_string()),
         })
     }
 
@@ -15,9 +15,131 @@ pub struct Extras {
     // Future extension point for fetcher data
     pub mock_data: Option<i32>,
     pub color: Option<ColorData>,
+    /// Whether the block can be collected in survival, when the source data says so
+    pub obtainable: Option<bool>,
+    /// Mining hardness from the source data; negative for unbreakable blocks
+    pub hardness: Option<f32>,
+    /// Human-readable English name from the source data (e.g. "Block of Gold")
+    pub display_name: Option<&'static str>,
     pub bedrock: Option<BedrockData>,
 }
 
+/// How a block can be acquired as an item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Obtainability {
+    /// Can be collected in survival mode
+    Survival,
+    /// Available from the creative inventory, but never in survival (e.g. bedrock)
+    CreativeOnly,
+    /// Only reachable through commands, or has no item form at all (e.g. command blocks)
+    Unobtainable,
+}
+
+/// Broad material category of a block, derived from its ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockCategory {
+    /// Stone, bricks, ores and other masonry
+    Stone,
+    /// Logs, planks and other wooden blocks
+    Wood,
+    /// Concrete and concrete powder
+    Concrete,
+    /// Plain and dyed terracotta, including glazed
+    Terracotta,
+    /// Wool and carpets
+    Wool,
+    /// Glass blocks and panes
+    Glass,
+    /// Metal blocks such as iron, gold, copper and netherite
+    Metal,
+    /// Leaves, saplings, flowers and other vegetation
+    Plant,
+    /// Anything that doesn't fit the categories above
+    Other,
+}
+
+/// ID prefixes of the wood types; anything else with these (stairs, signs, ...) is wood
+const WOOD_TYPE_PREFIXES: &[&str] = &[
+    "oak_",
+    "spruce_",
+    "birch_",
+    "jungle_",
+    "acacia_",
+    "dark_oak_",
+    "pale_oak_",
+    "mangrove_",
+    "cherry_",
+    "bamboo_",
+    "crimson_",
+    "warped_",
+];
+
+/// ID words that mark a block as stone for [`BlockFacts::category`]
+const STONE_WORDS: &[&str] = &[
+    "stone",
+    "cobblestone",
+    "brick",
+    "bricks",
+    "ore",
+    "deepslate",
+    "basalt",
+    "blackstone",
+    "sandstone",
+    "andesite",
+    "granite",
+    "diorite",
+    "tuff",
+    "calcite",
+    "netherrack",
+    "nylium",
+    "prismarine",
+    "purpur",
+    "quartz",
+];
+
+/// ID words that mark a block as a plant for [`BlockFacts::category`]
+const PLANT_WORDS: &[&str] = &[
+    "leaves",
+    "sapling",
+    "flower",
+    "fern",
+    "vine",
+    "vines",
+    "mushroom",
+    "fungus",
+    "roots",
+    "kelp",
+    "seagrass",
+    "grass",
+    "tulip",
+    "orchid",
+    "dandelion",
+    "poppy",
+    "azalea",
+    "cactus",
+    "cane",
+    "bush",
+    "lilac",
+    "peony",
+    "allium",
+    "bluet",
+    "lily",
+    "sunflower",
+    "cornflower",
+    "stem",
+    "moss",
+    "wart",
+    "propagule",
+    "dripleaf",
+    "sprouts",
+    "blossom",
+    "daisy",
+    "rose",
+    "torchflower",
+    "eyeblossom",
+    "petals",
+];
+
 #[derive(Debug, Clone, Copy)]
 pub struct BedrockData {
     pub id: &'static str,
@@ -52,6 +174,9 @@ impl Extras {
         Extras {
             mock_data: None,
             color: None,
+            obtainable: None,
+            hardness: None,
+            display_name: None,
             bedrock: None,
         }
     }
@@ -83,10 +208,14 @@ impl BlockFacts {
         self.properties.iter().any(|(key, _)| *key == property)
     }
 
+    /// Get the allowed values of a property
+    ///
+    /// Returns `None` for unknown properties and for malformed properties without any
+    /// allowed values, so callers never have to index into an empty list.
     pub fn get_property_values(&self, property: &str) -> Option<Vec<String>> {
         self.properties
             .iter()
-            .find(|(key, _)| *key == property)
+            .find(|(key, values)| *key == property && !values.is_empty())
             .map(|(_, values)| values.iter().map(|s| s.to_string()).collect())
     }
 
@@ -96,6 +225,181 @@ impl BlockFacts {
             .find(|(key, _)| *key == property)
             .map(|(_, value)| *value)
     }
+
+    /// Human-readable name, e.g. "Oak Planks"
+    ///
+    /// Uses the name from the source data when available and falls back to title-casing
+    /// the ID otherwise.
+    pub fn display_name(&self) -> String {
+        match self.extras.display_name {
+            Some(name) => name.to_string(),
+            None => title_case_id(self.id),
+        }
+    }
+
+    /// Check whether the block can be in a state satisfying a partial state pattern
+    ///
+    /// The pattern may name the block (`minecraft:lever[powered=true]`) or only list
+    /// properties (`[facing=north]` or `facing=north`); `*` accepts any value. Every listed
+    /// property must exist on the block with the value among its allowed values.
+    /// Malformed patterns match nothing.
+    pub fn matches_state(&self, pattern: &str) -> bool {
+        let pattern = pattern.trim();
+        let bracketed;
+        let pattern = if !pattern.contains('[') && pattern.contains('=') {
+            bracketed = format!("[{}]", pattern);
+            bracketed.as_str()
+        } else {
+            pattern
+        };
+
+        let Ok(parsed) = BlockState::parse_unvalidated(pattern) else {
+            return false;
+        };
+
+        let strip = |id: &str| id.strip_prefix("minecraft:").unwrap_or(id).to_string();
+        if !parsed.block_id.is_empty() && strip(&parsed.block_id) != strip(self.id) {
+            return false;
+        }
+
+        parsed.properties.iter().all(|(name, value)| {
+            self.properties.iter().any(|(prop, values)| {
+                *prop == name && (value == "*" || values.contains(&value.as_str()))
+            })
+        })
+    }
+
+    /// Classify how the block can be acquired
+    ///
+    /// The data-derived [`Extras::obtainable`] flag takes precedence; blocks without it
+    /// fall back to id-based heuristics.
+    pub fn obtainability(&self) -> Obtainability {
+        let id = self.id.strip_prefix("minecraft:").unwrap_or(self.id);
+        // Operator-only and technical blocks that never show up in the creative inventory
+        let command_only = matches!(
+            id,
+            "air"
+                | "cave_air"
+                | "void_air"
+                | "moving_piston"
+                | "nether_portal"
+                | "end_portal"
+                | "end_gateway"
+                | "command_block"
+                | "chain_command_block"
+                | "repeating_command_block"
+                | "barrier"
+                | "light"
+                | "structure_block"
+                | "structure_void"
+                | "jigsaw"
+        );
+
+        match self.extras.obtainable {
+            Some(true) => Obtainability::Survival,
+            Some(false) if command_only => Obtainability::Unobtainable,
+            Some(false) => Obtainability::CreativeOnly,
+            None if command_only || !query_builder::BlockQuery::is_survival_obtainable(self) => {
+                Obtainability::Unobtainable
+            }
+            None if id.starts_with("infested_")
+                || matches!(
+                    id,
+                    "budding_amethyst" | "spawner" | "reinforced_deepslate" | "petrified_oak_slab"
+                ) =>
+            {
+                Obtainability::CreativeOnly
+            }
+            None => Obtainability::Survival,
+        }
+    }
+
+    /// Classify the block by material
+    ///
+    /// Keywords are matched against whole `_`-separated words of the ID, so
+    /// `redstone_lamp` is not stone. Categories are checked in a fixed order and an ID
+    /// matching several gets the first one (e.g. `stone_brick_wall` is stone,
+    /// `oak_leaves` is a plant but `oak_stairs` is wood).
+    pub fn category(&self) -> BlockCategory {
+        let id = self.id.strip_prefix("minecraft:").unwrap_or(self.id);
+        let words: Vec<&str> = id.split('_').collect();
+        let has = |keywords: &[&str]| words.iter().any(|word| keywords.contains(word));
+        let wood_type = WOOD_TYPE_PREFIXES.iter().any(|prefix| {
+            id.strip_prefix("stripped_")
+                .unwrap_or(id)
+                .starts_with(prefix)
+        });
+
+        if has(&["glass"]) {
+            BlockCategory::Glass
+        } else if has(&["wool"]) || (has(&["carpet"]) && !has(&["moss"])) {
+            BlockCategory::Wool
+        } else if has(&["concrete"]) {
+            BlockCategory::Concrete
+        } else if has(&["terracotta"]) {
+            BlockCategory::Terracotta
+        } else if has(&["wood", "planks", "log", "hyphae"]) || (wood_type && has(&["stem"])) {
+            BlockCategory::Wood
+        } else if (has(PLANT_WORDS) && !matches!(id, "grass_block" | "flower_pot"))
+            || matches!(id, "bamboo" | "bamboo_sapling" | "potted_bamboo")
+        {
+            BlockCategory::Plant
+        } else if has(STONE_WORDS) {
+            BlockCategory::Stone
+        } else if wood_type {
+            BlockCategory::Wood
+        } else if has(&["metal", "iron", "gold", "copper", "netherite"]) {
+            BlockCategory::Metal
+        } else {
+            BlockCategory::Other
+        }
+    }
+    /// Check whether the block does something when right-clicked or opens a GUI
+    ///
+    /// This covers every tile entity plus plain blocks with interactions such as
+    /// levers, buttons, doors, crafting tables and note blocks.
+    pub fn is_interactive(&self) -> bool {
+        if query_builder::BlockQuery::is_tile_entity(self) {
+            return true;
+        }
+
+        let id = self.id.strip_prefix("minecraft:").unwrap_or(self.id);
+        id.ends_with("_bed")
+            || id.ends_with("_button")
+            // Iron doors and trapdoors only respond to redstone
+            || (id.ends_with("door") && !matches!(id, "iron_door" | "iron_trapdoor"))
+            || id.ends_with("fence_gate")
+            || matches!(
+                id,
+                "lever"
+                    | "crafting_table"
+                    | "note_block"
+                    | "repeater"
+                    | "comparator"
+                    | "daylight_detector"
+                    | "anvil"
+                    | "chipped_anvil"
+                    | "damaged_anvil"
+                    | "grindstone"
+                    | "stonecutter"
+                    | "loom"
+                    | "cartography_table"
+                    | "smithing_table"
+                    | "fletching_table"
+                    | "crafter"
+                    | "bell"
+                    | "cake"
+                    | "composter"
+                    | "respawn_anchor"
+                    | "beehive"
+                    | "bee_nest"
+                    | "command_block"
+                    | "chain_command_block"
+                    | "repeating_command_block"
+                    | "structure_block"
+                    | "jigsaw"
+            )
+    }
 }
 
 impl BlockState {
@@ -111,6 +415,31 @@ impl BlockState {
         &self.properties
     }
 
+    /// Check whether every property the block defines has an explicit value in this state
+    ///
+    /// Unlike a default comparison, this only asks whether the state is fully specified,
+    /// i.e. nothing would need to be filled in from defaults before serialization.
+    pub fn properties_complete(&self) -> bool {
+        match BLOCKS.get(&self.block_id) {
+            Some(facts) => facts
+                .properties
+                .iter()
+                .all(|(name, _)| self.properties.contains_key(*name)),
+            None => false,
+        }
+    }
+
+    /// Like [`BlockState::new`], but accepts bare names such as `stone`
+    ///
+    /// See [`get_block_lenient`] for how the ID is resolved. Errors still report the ID
+    /// as it was passed in.
+    pub fn new_lenient(block_id: &str) -> Result<Self> {
+        match get_block_lenient(block_id) {
+            Some(facts) => Self::new(facts.id),
+            None => Self::new(block_id),
+        }
+    }
+
     pub fn new(block_id: &str) -> Result<Self> {
         // Validate block ID format first
         errors::validation::validate_block_id(block_id)?;
@@ -127,6 +456,14 @@ impl BlockState {
     }
 
     pub fn with(mut self, property: &str, value: &str) -> Result<Self> {
+        self.set_property(property, value)?;
+        Ok(self)
+    }
+
+    /// Set a property in place, applying the same validation as [`BlockState::with`]
+    ///
+    /// On error the state is left untouched.
+    pub fn set_property(&mut self, property: &str, value: &str) -> Result<()> {
         // Validate property name format
         errors::validation::validate_property_name(property)?;
 
@@ -162,10 +499,22 @@ impl BlockState {
 
         self.properties
             .insert(property.to_string(), value.to_string());
-        Ok(self)
+        Ok(())
+    }
+
+    /// Remove an explicitly set property, returning its previous value
+    ///
+    /// The property falls back to the block's default when the state is serialized.
+    pub fn remove_property(&mut self, property: &str) -> Option<String> {
+        self.properties.remove(property)
     }
 
     /// Create a BlockState from the default state of a block
+    ///
+    /// Any property without a recorded default (tables built from PrismarineJS data
+    /// have none, and invalid defaults are dropped at build time) falls back to its first
+    /// allowed value, the same way `to_bedrock` fills in missing properties. Properties
+    /// without any allowed values are left unset.
     pub fn from_default(block_facts: &BlockFacts) -> Result<Self> {
         let mut state = BlockState {
             block_id: block_facts.id().to_string(),
@@ -179,6 +528,18 @@ impl BlockState {
                 .insert(property.to_string(), value.to_string());
         }
 
+        // For any properties that don't have defaults, use the first allowed value
+        for (property, values) in block_facts.properties {
+            if state.properties.contains_key(*property) {
+                continue;
+            }
+            if let Some(first) = values.first() {
+                state
+                    .properties
+                    .insert(property.to_string(), first.to_string());
+            }
+        }
+
         Ok(state)
     }
 
@@ -189,9 +550,11 @@ impl BlockState {
             let properties_str = &blockstate_str[bracket_pos + 1..];
 
             if !properties_str.ends_with(']') {
-                return Err(BlockpediaError::parse_failed(
+                return Err(BlockpediaError::parse_failed_at(
                     blockstate_str,
                     "missing closing bracket",
+                    blockstate_str.len(),
+                    &blockstate_str[bracket_pos..],
                 ));
             }
 
@@ -199,15 +562,19 @@ impl BlockState {
             let mut properties = HashMap::new();
 
             if !properties_str.is_empty() {
+                let mut offset = bracket_pos + 1;
                 for prop_pair in properties_str.split(',') {
                     let parts: Vec<&str> = prop_pair.split('=').collect();
                     if parts.len() != 2 {
-                        return Err(BlockpediaError::parse_failed(
+                        return Err(BlockpediaError::parse_failed_at(
                             blockstate_str,
                             &format!("invalid property format: {}", prop_pair),
+                            offset,
+                            prop_pair,
                         ));
                     }
                     properties.insert(parts[0].trim().to_string(), parts[1].trim().to_string());
+                    offset += prop_pair.len() + 1;
                 }
             }
 
@@ -231,9 +598,11 @@ impl BlockState {
             let properties_str = &blockstate_str[bracket_pos + 1..];
 
             if !properties_str.ends_with(']') {
-                return Err(BlockpediaError::parse_failed(
+                return Err(BlockpediaError::parse_failed_at(
                     blockstate_str,
                     "missing closing bracket",
+                    blockstate_str.len(),
+                    &blockstate_str[bracket_pos..],
                 ));
             }
 
@@ -241,15 +610,19 @@ impl BlockState {
             let mut state = BlockState::new(block_id)?;
 
             if !properties_str.is_empty() {
+                let mut offset = bracket_pos + 1;
                 for prop_pair in properties_str.split(',') {
                     let parts: Vec<&str> = prop_pair.split('=').collect();
                     if parts.len() != 2 {
-                        return Err(BlockpediaError::parse_failed(
+                        return Err(BlockpediaError::parse_failed_at(
                             blockstate_str,
                             &format!("invalid property format: {}", prop_pair),
+                            offset,
+                            prop_pair,
                         ));
                     }
                     state = state.with(parts[0].trim(), parts[1].trim())?;
+                    offset += prop_pair.len() + 1;
                 }
             }
 
@@ -485,23 +858,98 @@ pub use color::ExtendedColorData;
 // Query builder module for chained filtering
 pub mod query_builder;
 pub use query_builder::{
-    AllBlocks, BlockQuery, ColorSamplingMethod, ColorSpace, EasingFunction, GradientConfig,
+    AllBlocks, BlockQuery, ColorSamplingMethod, ColorSpace, ColorTemperatureGroups, EasingFunction,
+    GradientConfig,
 };
 
 // Block transformation module for rotation and variants
 pub mod transforms;
 pub use transforms::{BlockShape, BlockTransforms, Direction, Rotation};
 
+// One-call block details for UIs
+pub mod block_info;
+pub use block_info::{block_info, BlockColorInfo, BlockInfo};
+
 /// Get a block by its string ID
 pub fn get_block(id: &str) -> Option<&'static BlockFacts> {
     BLOCKS.get(id).copied()
 }
 
+/// Get a block by ID, assuming the `minecraft:` namespace when none is given
+///
+/// `stone` resolves to `minecraft:stone`. Only the default namespace is added; IDs that
+/// already have a namespace, and bare names that still don't match, return `None`.
+pub fn get_block_lenient(id: &str) -> Option<&'static BlockFacts> {
+    get_block(id).or_else(|| {
+        if id.contains(':') {
+            None
+        } else {
+            get_block(&format!("minecraft:{}", id))
+        }
+    })
+}
+
 /// Get all blocks as an iterator
 pub fn all_blocks() -> impl Iterator<Item = &'static BlockFacts> {
     BLOCKS.values().copied()
 }
 
+/// Get a human-readable name for a block ID
+///
+/// Unknown IDs are title-cased, so this always returns something presentable.
+pub fn display_name(id: &str) -> String {
+    match BLOCKS.get(id) {
+        Some(block) => block.display_name(),
+        None => title_case_id(id),
+    }
+}
+
+/// Turn `minecraft:oak_planks` into `Oak Planks`
+fn title_case_id(id: &str) -> String {
+    id.strip_prefix("minecraft:")
+        .unwrap_or(id)
+        .replace('_', " ")
+        .split_whitespace()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Iterate over all blocks matching a predicate
+///
+/// A lightweight alternative to [`BlockQuery`] for one-off filters.
+///
+/// ```
+/// let transparent: Vec<_> = blockpedia::filter_blocks(|block| block.transparent).collect();
+/// assert!(transparent.iter().any(|block| block.id() == "minecraft:glass"));
+/// assert!(transparent.iter().all(|block| block.transparent));
+/// ```
+pub fn filter_blocks<F>(f: F) -> impl Iterator<Item = &'static BlockFacts>
+where
+    F: Fn(&BlockFacts) -> bool,
+{
+    all_blocks().filter(move |block| f(block))
+}
+
+/// Parse a newline-delimited list of blockstate strings
+///
+/// Each entry pairs the 1-based line number with that line's parse result, so one bad
+/// line doesn't abort the whole import. Blank lines and `#` comments are skipped.
+pub fn parse_block_list(text: &str) -> Vec<(usize, Result<BlockState>)> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(number, line)| (number, BlockState::parse(line)))
+        .collect()
+}
+
 // WASM bindings
 #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
 mod wasm;
@@ -1,5 +1,6 @@
 use crate::{errors::*, BlockFacts, Result, BLOCKS};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::OnceLock;
 
 /// Find all blocks that have a specific property with a specific value
 pub fn find_blocks_by_property(
@@ -103,6 +104,44 @@ pub fn get_property_values(property: &str) -> Option<Vec<String>> {
     }
 }
 
+/// Get the union of allowed values for several properties across all blocks
+pub fn property_values_union(props: &[&str]) -> BTreeSet<String> {
+    BLOCKS
+        .values()
+        .flat_map(|block| block.properties.iter())
+        .filter(|(name, _)| props.contains(name))
+        .flat_map(|(_, values)| values.iter().map(|value| value.to_string()))
+        .collect()
+}
+
+/// Get every property name with the sorted union of its values across all blocks
+pub fn get_all_properties() -> HashMap<String, Vec<String>> {
+    let mut all_props: HashMap<String, Vec<String>> = HashMap::new();
+    for block in BLOCKS.values() {
+        for (prop, values) in block.properties {
+            let entry = all_props.entry(prop.to_string()).or_default();
+            for value in values.iter() {
+                if !entry.iter().any(|v| v == value) {
+                    entry.push(value.to_string());
+                }
+            }
+        }
+    }
+    for values in all_props.values_mut() {
+        values.sort();
+    }
+    all_props
+}
+
+/// Cached variant of [`get_all_properties`]
+///
+/// The block table is static, so the result is computed once on first use and
+/// shared afterwards. Prefer this in hot paths such as per-frame UI rendering.
+pub fn get_all_properties_cached() -> &'static HashMap<String, Vec<String>> {
+    static ALL_PROPERTIES: OnceLock<HashMap<String, Vec<String>>> = OnceLock::new();
+    ALL_PROPERTIES.get_or_init(get_all_properties)
+}
+
 /// Count blocks that match a predicate
 pub fn count_blocks_where<F>(predicate: F) -> usize
 where
@@ -1,4 +1,10 @@
-use crate::{color::ExtendedColorData, BlockFacts, BLOCKS};
+use crate::{
+    color::{ColorTemperature, ExtendedColorData},
+    BlockCategory, BlockFacts, Obtainability, BLOCKS,
+};
+use palette::{IntoColor, Oklch, Srgb};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::collections::HashSet;
 
 /// Main entry point for block queries - works with BlockFacts throughout
@@ -25,7 +31,7 @@ pub enum ColorSamplingMethod {
 /// Color space for gradient interpolation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColorSpace {
-    /// RGB color space (simple linear interpolation)
+    /// RGB color space (gamma-correct interpolation in linear light)
     Rgb,
     /// HSL color space (good for hue transitions)
     Hsl,
@@ -33,6 +39,8 @@ pub enum ColorSpace {
     Oklab,
     /// CIE Lab color space (professional color work)
     Lab,
+    /// Oklch color space (keeps chroma up, hue travels the shortest way around)
+    Oklch,
 }
 
 /// Easing functions for gradient generation
@@ -63,6 +71,16 @@ pub struct GradientConfig {
     pub easing: EasingFunction,
 }
 
+/// Blocks partitioned by color temperature, see [`BlockQuery::split_by_color_temperature`]
+#[derive(Debug, Clone, Default)]
+pub struct ColorTemperatureGroups {
+    pub warm: Vec<&'static BlockFacts>,
+    pub neutral: Vec<&'static BlockFacts>,
+    pub cool: Vec<&'static BlockFacts>,
+    /// Blocks without color data
+    pub uncolored: Vec<&'static BlockFacts>,
+}
+
 impl Default for GradientConfig {
     fn default() -> Self {
         Self {
@@ -74,6 +92,9 @@ impl Default for GradientConfig {
     }
 }
 
+/// Minimum Oklab distance between any two colors picked by [`BlockQuery::first_n_distinct_colors`]
+pub const DISTINCT_COLOR_MIN_DISTANCE: f32 = 0.05;
+
 /// Main entry point - all blocks
 pub struct AllBlocks;
 
@@ -102,6 +123,12 @@ impl BlockQuery {
         self
     }
 
+    /// Exclude blocks with right-click behavior or a GUI (see [`BlockFacts::is_interactive`])
+    pub fn exclude_interactive(mut self) -> Self {
+        self.blocks.retain(|block| !block.is_interactive());
+        self
+    }
+
     /// Exclude blocks that fall due to gravity
     pub fn exclude_falling(mut self) -> Self {
         self.blocks.retain(|block| !Self::is_falling_block(block));
@@ -126,10 +153,23 @@ impl BlockQuery {
         self
     }
 
-    /// Only include blocks obtainable in survival mode
+    /// Only include blocks obtainable in survival mode (see [`BlockFacts::obtainability`])
     pub fn survival_only(mut self) -> Self {
         self.blocks
-            .retain(|block| Self::is_survival_obtainable(block));
+            .retain(|block| block.obtainability() == Obtainability::Survival);
+        self
+    }
+
+    /// Only include blocks in the given material category (see [`BlockFacts::category`])
+    pub fn in_category(mut self, category: BlockCategory) -> Self {
+        self.blocks.retain(|block| block.category() == category);
+        self
+    }
+
+    /// Exclude blocks in any of the given material categories
+    pub fn exclude_category(mut self, categories: &[BlockCategory]) -> Self {
+        self.blocks
+            .retain(|block| !categories.contains(&block.category()));
         self
     }
 
@@ -206,6 +246,43 @@ impl BlockQuery {
         self
     }
 
+    /// Keep only blocks within `tolerance` (Oklab distance) of any color in a fixed palette
+    pub fn restrict_to_colors(mut self, palette: &[ExtendedColorData], tolerance: f32) -> Self {
+        self.blocks.retain(|block| {
+            if let Some(color) = block.extras.color {
+                let color = color.to_extended();
+                palette
+                    .iter()
+                    .any(|target| color.distance_oklab(target) <= tolerance)
+            } else {
+                false
+            }
+        });
+        self
+    }
+
+    /// Keep colored blocks whose perceived brightness (0.0-1.0) is above `threshold`
+    pub fn brighter_than(mut self, threshold: f32) -> Self {
+        self.blocks.retain(|block| {
+            block
+                .extras
+                .color
+                .is_some_and(|color| color.to_extended().perceived_brightness() > threshold)
+        });
+        self
+    }
+
+    /// Keep colored blocks whose perceived brightness (0.0-1.0) is below `threshold`
+    pub fn darker_than(mut self, threshold: f32) -> Self {
+        self.blocks.retain(|block| {
+            block
+                .extras
+                .color
+                .is_some_and(|color| color.to_extended().perceived_brightness() < threshold)
+        });
+        self
+    }
+
     /// Limit the number of results
     pub fn limit(mut self, count: usize) -> Self {
         self.blocks.truncate(count);
@@ -219,23 +296,19 @@ impl BlockQuery {
     }
 
     /// Sort blocks by color similarity to a reference color
-    pub fn sort_by_color_similarity(mut self, reference: ExtendedColorData) -> Self {
-        self.blocks.sort_by(|a, b| {
-            let dist_a = a
-                .extras
-                .color
-                .map(|c| c.to_extended().distance_oklab(&reference))
-                .unwrap_or(f32::INFINITY);
-            let dist_b = b
-                .extras
-                .color
-                .map(|c| c.to_extended().distance_oklab(&reference))
-                .unwrap_or(f32::INFINITY);
-            dist_a
-                .partial_cmp(&dist_b)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        self
+    ///
+    /// Distances are computed once per block up front (in parallel with the `parallel`
+    /// feature) and the sort itself is stable, so the ordering is identical either way.
+    pub fn sort_by_color_similarity(self, reference: ExtendedColorData) -> Self {
+        let colors = Self::extended_colors(&self.blocks);
+        let distances = Self::distances_to(&colors, &reference);
+
+        let mut keyed: Vec<_> = distances.into_iter().zip(self.blocks).collect();
+        keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        BlockQuery {
+            blocks: keyed.into_iter().map(|(_, block)| block).collect(),
+        }
     }
 
     // === TERMINAL METHODS (return Vec<BlockFacts> or other types) ===
@@ -245,6 +318,73 @@ impl BlockQuery {
         self.blocks
     }
 
+    /// Get the colored blocks paired with their resolved colors, skipping blocks without color data
+    pub fn collect_with_colors(self) -> Vec<(&'static BlockFacts, ExtendedColorData)> {
+        self.blocks
+            .into_iter()
+            .filter_map(|block| block.extras.color.map(|color| (block, color.to_extended())))
+            .collect()
+    }
+
+    /// Get up to `n` blocks whose colors are all visibly different from each other
+    ///
+    /// Walks the blocks in their current order and greedily keeps each one whose color is
+    /// at least [`DISTINCT_COLOR_MIN_DISTANCE`] (Oklab) from every block kept so far.
+    /// Blocks without color data are skipped.
+    pub fn first_n_distinct_colors(self, n: usize) -> Vec<&'static BlockFacts> {
+        let mut picked: Vec<(&'static BlockFacts, ExtendedColorData)> = Vec::new();
+        for (block, color) in self.collect_with_colors() {
+            if picked.len() == n {
+                break;
+            }
+            if picked
+                .iter()
+                .all(|(_, kept)| kept.distance_oklab(&color) >= DISTINCT_COLOR_MIN_DISTANCE)
+            {
+                picked.push((block, color));
+            }
+        }
+        picked.into_iter().map(|(block, _)| block).collect()
+    }
+
+    /// Partition the blocks by color temperature in a single pass
+    pub fn split_by_color_temperature(self) -> ColorTemperatureGroups {
+        let mut groups = ColorTemperatureGroups::default();
+        for block in self.blocks {
+            match block.extras.color.map(|c| c.to_extended().temperature()) {
+                Some(ColorTemperature::Warm) => groups.warm.push(block),
+                Some(ColorTemperature::Neutral) => groups.neutral.push(block),
+                Some(ColorTemperature::Cool) => groups.cool.push(block),
+                None => groups.uncolored.push(block),
+            }
+        }
+        groups
+    }
+
+    /// Render the blocks as a GitHub-flavored Markdown table (non-consuming)
+    ///
+    /// Columns are the block ID, its color as hex (`-` when the block has no color data)
+    /// and how many properties it has. Pipe characters in values are escaped.
+    pub fn to_markdown_table(&self) -> String {
+        let escape = |value: &str| value.replace('|', "\\|");
+
+        let mut table = String::from("| ID | Color | Properties |\n|---|---|---|\n");
+        for block in &self.blocks {
+            let color = block
+                .extras
+                .color
+                .map(|c| c.to_extended().hex_string())
+                .unwrap_or_else(|| "-".to_string());
+            table.push_str(&format!(
+                "| {} | {} | {} |\n",
+                escape(block.id),
+                escape(&color),
+                block.properties.len()
+            ));
+        }
+        table
+    }
+
     /// Get the count of matching blocks (consumes the query)
     pub fn count(self) -> usize {
         self.blocks.len()
@@ -260,6 +400,19 @@ impl BlockQuery {
         self.blocks.is_empty()
     }
 
+    /// Fraction of the current results that have color data, 0.0 for an empty query (non-consuming)
+    pub fn color_coverage(&self) -> f32 {
+        if self.blocks.is_empty() {
+            return 0.0;
+        }
+        let colored = self
+            .blocks
+            .iter()
+            .filter(|block| block.extras.color.is_some())
+            .count();
+        colored as f32 / self.blocks.len() as f32
+    }
+
     /// Get the first block (if any)
     pub fn first(self) -> Option<&'static BlockFacts> {
         self.blocks.into_iter().next()
@@ -415,23 +568,22 @@ impl BlockQuery {
         }
 
         // Use traveling salesman-like approach to create smooth color transitions
-        let mut result = Vec::new();
+        let mut colors: Vec<ExtendedColorData> = Self::extended_colors(&colored_blocks)
+            .into_iter()
+            .map(|color| color.unwrap())
+            .collect();
+
+        let mut result = Vec::with_capacity(colored_blocks.len());
         result.push(colored_blocks.remove(0)); // Start with first block
+        let mut current_color = colors.remove(0);
 
         while !colored_blocks.is_empty() {
-            let current_color = result.last().unwrap().extras.color.unwrap().to_extended();
-
-            // Find the closest remaining color
+            // Find the closest remaining color; the first minimum wins ties
+            let distances = Self::distances_to_colors(&colors, &current_color);
             let mut best_index = 0;
             let mut best_distance = f32::INFINITY;
 
-            for (i, block) in colored_blocks.iter().enumerate() {
-                let distance = block
-                    .extras
-                    .color
-                    .unwrap()
-                    .to_extended()
-                    .distance_oklab(&current_color);
+            for (i, distance) in distances.into_iter().enumerate() {
                 if distance < best_distance {
                     best_distance = distance;
                     best_index = i;
@@ -439,6 +591,7 @@ impl BlockQuery {
             }
 
             result.push(colored_blocks.remove(best_index));
+            current_color = colors.remove(best_index);
         }
 
         BlockQuery { blocks: result }
@@ -446,6 +599,48 @@ impl BlockQuery {
 
     // === HELPER METHODS ===
 
+    /// Resolve the extended color of each block (in parallel with the `parallel` feature)
+    fn extended_colors(blocks: &[&'static BlockFacts]) -> Vec<Option<ExtendedColorData>> {
+        #[cfg(feature = "parallel")]
+        let iter = blocks.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = blocks.iter();
+
+        iter.map(|block| block.extras.color.map(|c| c.to_extended()))
+            .collect()
+    }
+
+    /// Oklab distance from each color to the reference, with missing colors sorting last
+    fn distances_to(
+        colors: &[Option<ExtendedColorData>],
+        reference: &ExtendedColorData,
+    ) -> Vec<f32> {
+        #[cfg(feature = "parallel")]
+        let iter = colors.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = colors.iter();
+
+        iter.map(|color| {
+            color
+                .map(|c| c.distance_oklab(reference))
+                .unwrap_or(f32::INFINITY)
+        })
+        .collect()
+    }
+
+    /// Oklab distance from each color to the reference
+    fn distances_to_colors(
+        colors: &[ExtendedColorData],
+        reference: &ExtendedColorData,
+    ) -> Vec<f32> {
+        #[cfg(feature = "parallel")]
+        let iter = colors.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = colors.iter();
+
+        iter.map(|color| color.distance_oklab(reference)).collect()
+    }
+
     fn is_solid_block(block: &BlockFacts) -> bool {
         let id = block.id().to_lowercase();
         !matches!(id.as_str(),
@@ -467,7 +662,7 @@ impl BlockQuery {
         )
     }
 
-    fn is_tile_entity(block: &BlockFacts) -> bool {
+    pub(crate) fn is_tile_entity(block: &BlockFacts) -> bool {
         let id = block.id().to_lowercase();
         matches!(id.as_str(),
             id if id.contains("chest") ||
@@ -514,7 +709,7 @@ impl BlockQuery {
         )
     }
 
-    fn is_light_source(block: &BlockFacts) -> bool {
+    pub(crate) fn is_light_source(block: &BlockFacts) -> bool {
         let id = block.id().to_lowercase();
         matches!(id.as_str(),
             id if id.contains("torch") ||
@@ -566,7 +761,7 @@ impl BlockQuery {
         )
     }
 
-    fn is_survival_obtainable(block: &BlockFacts) -> bool {
+    pub(crate) fn is_survival_obtainable(block: &BlockFacts) -> bool {
         let id = block.id().to_lowercase();
         !matches!(id.as_str(),
             id if id.contains("barrier") ||
@@ -794,6 +989,7 @@ impl BlockQuery {
             ColorSpace::Hsl => Self::interpolate_hsl(start, end, t),
             ColorSpace::Oklab => Self::interpolate_oklab(start, end, t),
             ColorSpace::Lab => Self::interpolate_lab(start, end, t),
+            ColorSpace::Oklch => Self::interpolate_oklch(start, end, t),
         }
     }
 
@@ -802,10 +998,7 @@ impl BlockQuery {
         end: ExtendedColorData,
         t: f32,
     ) -> ExtendedColorData {
-        let r = (start.rgb[0] as f32 * (1.0 - t) + end.rgb[0] as f32 * t) as u8;
-        let g = (start.rgb[1] as f32 * (1.0 - t) + end.rgb[1] as f32 * t) as u8;
-        let b = (start.rgb[2] as f32 * (1.0 - t) + end.rgb[2] as f32 * t) as u8;
-        ExtendedColorData::from_rgb(r, g, b)
+        start.lerp_gamma_correct(&end, t)
     }
 
     fn interpolate_hsl(
@@ -850,6 +1043,31 @@ impl BlockQuery {
         ExtendedColorData::from_rgb(rgb[0], rgb[1], rgb[2])
     }
 
+    fn interpolate_oklch(
+        start: ExtendedColorData,
+        end: ExtendedColorData,
+        t: f32,
+    ) -> ExtendedColorData {
+        // Grays have no meaningful hue, so borrow the other endpoint's
+        const ACHROMATIC_CHROMA: f32 = 1e-4;
+        let (start_hue, end_hue) = match (
+            start.oklch[1] < ACHROMATIC_CHROMA,
+            end.oklch[1] < ACHROMATIC_CHROMA,
+        ) {
+            (true, false) => (end.oklch[2], end.oklch[2]),
+            (false, true) => (start.oklch[2], start.oklch[2]),
+            _ => (start.oklch[2], end.oklch[2]),
+        };
+
+        let l = start.oklch[0] * (1.0 - t) + end.oklch[0] * t;
+        let c = start.oklch[1] * (1.0 - t) + end.oklch[1] * t;
+        let h = Self::interpolate_hue(start_hue, end_hue, t);
+
+        let srgb: Srgb = Oklch::new(l, c, h).into_color();
+        let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        ExtendedColorData::from_rgb(channel(srgb.red), channel(srgb.green), channel(srgb.blue))
+    }
+
     fn interpolate_hue(start_hue: f32, end_hue: f32, t: f32) -> f32 {
         let mut diff = end_hue - start_hue;
 
@@ -1055,6 +1273,21 @@ impl GradientConfig {
         }
     }
 
+    /// Perceptually even gradient that eases in and out of the endpoints (Oklab, 10 steps)
+    pub fn smooth() -> Self {
+        Self::default().with_easing(EasingFunction::EaseInOut)
+    }
+
+    /// Saturated gradient that goes around the hue wheel rather than through gray (Oklch, 10 steps)
+    pub fn vivid() -> Self {
+        Self::default().with_color_space(ColorSpace::Oklch)
+    }
+
+    /// Plain RGB gradient with linear spacing, the cheapest option
+    pub fn quick(steps: usize) -> Self {
+        Self::new(steps).with_color_space(ColorSpace::Rgb)
+    }
+
     pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
         self.color_space = color_space;
         self
@@ -58,6 +58,26 @@ mod milestone7_tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn lenient_lookup_adds_default_namespace() {
+        let stone = crate::get_block_lenient("stone").unwrap();
+        assert_eq!(stone.id, "minecraft:stone");
+        assert_eq!(
+            crate::get_block_lenient("minecraft:stone").unwrap().id,
+            "minecraft:stone"
+        );
+        assert!(crate::get_block("stone").is_none());
+        assert!(crate::get_block_lenient("notareal").is_none());
+        assert!(crate::get_block_lenient("other:stone").is_none());
+
+        let state = crate::BlockState::new_lenient("stone").unwrap();
+        assert_eq!(state.to_string(), "minecraft:stone");
+        assert!(crate::BlockState::new("stone").is_err());
+
+        let err = crate::BlockState::new_lenient("notareal").unwrap_err();
+        assert!(err.to_string().contains("notareal"));
+    }
+
     #[test]
     fn validation_rejects_invalid_property_names() {
         // Empty property name
@@ -165,6 +185,47 @@ mod milestone7_tests {
         assert_ne!(broken_input, fixed);
     }
 
+    #[test]
+    fn parse_errors_report_offset_of_missing_bracket() {
+        let input = "minecraft:repeater[delay=3";
+        let error = crate::BlockState::parse(input).unwrap_err();
+
+        match &error {
+            BlockpediaError::State(StateError::ParseFailed {
+                position, token, ..
+            }) => {
+                assert_eq!(*position, Some(input.len()));
+                assert_eq!(token.as_deref(), Some("[delay=3"));
+            }
+            other => panic!("Expected ParseFailed, got {:?}", other),
+        }
+
+        // The caret sits just past the last character
+        let message = error.to_string();
+        let caret_line = message.lines().last().unwrap();
+        assert_eq!(caret_line, format!("  {}^", " ".repeat(input.len())));
+    }
+
+    #[test]
+    fn parse_errors_report_offset_of_malformed_token() {
+        let input = "minecraft:repeater[delay=3,facing=north=extra]";
+        let error = crate::BlockState::parse(input).unwrap_err();
+
+        match &error {
+            BlockpediaError::State(StateError::ParseFailed {
+                position, token, ..
+            }) => {
+                assert_eq!(*position, input.find("facing"));
+                assert_eq!(token.as_deref(), Some("facing=north=extra"));
+            }
+            other => panic!("Expected ParseFailed, got {:?}", other),
+        }
+
+        let message = error.to_string();
+        assert!(message.contains(&format!("\n  {}\n", input)));
+        assert!(message.ends_with(&format!("{}^", " ".repeat(input.find("facing").unwrap()))));
+    }
+
     #[test]
     fn structured_errors_are_comparable() {
         let error1 = BlockpediaError::block_not_found("test:block");
@@ -337,6 +398,77 @@ mod milestone5_tests {
     }
 }
 
+#[cfg(test)]
+mod data_source_tests {
+    use crate::data_sources::{invalid_json_default_state, UnifiedBlockData};
+    use std::collections::HashMap;
+
+    fn block(default_state: &[(&str, &str)]) -> UnifiedBlockData {
+        UnifiedBlockData {
+            id: "minecraft:lever".to_string(),
+            properties: HashMap::from([
+                (
+                    "facing".to_string(),
+                    vec!["north".to_string(), "south".to_string()],
+                ),
+                (
+                    "powered".to_string(),
+                    vec!["false".to_string(), "true".to_string()],
+                ),
+            ]),
+            default_state: default_state
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            transparent: false,
+            extra_properties: HashMap::new(),
+            bedrock_id: None,
+            bedrock_properties: None,
+            bedrock_default_state: None,
+        }
+    }
+
+    #[test]
+    fn consistent_default_state_passes() {
+        let valid = block(&[("facing", "north"), ("powered", "false")]);
+        assert!(valid.invalid_default_state().is_empty());
+    }
+
+    #[test]
+    fn malformed_default_state_is_detected() {
+        let malformed = block(&[
+            ("facing", "up"),
+            ("powered", "false"),
+            ("waterlogged", "false"),
+        ]);
+        assert_eq!(
+            malformed.invalid_default_state(),
+            vec![("facing", "up"), ("waterlogged", "false")]
+        );
+    }
+
+    #[test]
+    fn malformed_json_default_state_is_detected() {
+        let block: serde_json::Value = serde_json::json!({
+            "properties": {
+                "facing": ["north", "south"],
+                "powered": ["false", "true"]
+            },
+            "default_state": { "facing": "north", "powered": false, "lit": "true" }
+        });
+        assert_eq!(
+            invalid_json_default_state(
+                block["properties"].as_object().unwrap(),
+                block["default_state"].as_object().unwrap()
+            ),
+            vec![
+                ("lit".to_string(), "true".to_string()),
+                ("powered".to_string(), String::new())
+            ]
+        );
+    }
+}
+
 #[cfg(test)]
 mod milestone2_tests {
     use crate::{BlockState, BLOCKS};
@@ -441,6 +573,192 @@ mod milestone3_tests {
         assert_eq!(default_state.to_string(), rebuilt.to_string());
     }
 
+    #[test]
+    fn from_default_falls_back_to_first_allowed_values() {
+        // Properties are known but the source recorded no defaults
+        static NO_DEFAULTS: crate::BlockFacts = crate::BlockFacts {
+            id: "minecraft:repeater",
+            properties: &[
+                ("delay", &["1", "2", "3", "4"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            default_state: &[],
+            transparent: true,
+            extras: crate::Extras::new(),
+        };
+
+        let state = BlockState::from_default(&NO_DEFAULTS).unwrap();
+        assert_eq!(state.get_property("delay"), Some("1"));
+        assert_eq!(state.get_property("facing"), Some("north"));
+        assert_eq!(state.get_property("powered"), Some("true"));
+        assert_eq!(state.properties.len(), NO_DEFAULTS.properties.len());
+
+        // Only some properties have recorded defaults
+        static PARTIAL_DEFAULTS: crate::BlockFacts = crate::BlockFacts {
+            id: "minecraft:repeater",
+            properties: &[
+                ("delay", &["1", "2", "3", "4"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            default_state: &[("facing", "west")],
+            transparent: true,
+            extras: crate::Extras::new(),
+        };
+
+        let state = BlockState::from_default(&PARTIAL_DEFAULTS).unwrap();
+        assert_eq!(state.get_property("facing"), Some("west"));
+        assert_eq!(state.get_property("delay"), Some("1"));
+        assert_eq!(state.get_property("powered"), Some("true"));
+        assert_eq!(state.properties.len(), PARTIAL_DEFAULTS.properties.len());
+
+        // Recorded defaults are kept as-is
+        let stone = BLOCKS.get("minecraft:stone").unwrap();
+        let state = BlockState::from_default(stone).unwrap();
+        assert_eq!(state.to_string(), "minecraft:stone");
+    }
+
+    #[test]
+    fn parse_block_list_reports_line_numbers() {
+        let text = [
+            "# palette export",
+            "minecraft:stone",
+            "",
+            "minecraft:repeater[delay=3,facing=north]",
+            "minecraft:not_a_block",
+            "  # indented comment",
+            "minecraft:oak_planks",
+        ]
+        .join("\n");
+
+        let results = crate::parse_block_list(&text);
+        let lines: Vec<usize> = results.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![2, 4, 5, 7]);
+
+        assert_eq!(results[0].1.as_ref().unwrap().id(), "minecraft:stone");
+        assert_eq!(
+            results[1].1.as_ref().unwrap().get_property("delay"),
+            Some("3")
+        );
+        assert!(results[2].1.is_err());
+        assert_eq!(results[3].1.as_ref().unwrap().id(), "minecraft:oak_planks");
+    }
+
+    #[test]
+    fn empty_property_value_lists_do_not_panic() {
+        // Simulates a source that declared a property without any values
+        static MALFORMED: crate::BlockFacts = crate::BlockFacts {
+            id: "minecraft:malformed",
+            properties: &[("facing", &[]), ("powered", &["false", "true"])],
+            default_state: &[],
+            transparent: false,
+            extras: crate::Extras::new(),
+        };
+
+        assert!(MALFORMED.has_property("facing"));
+        assert_eq!(MALFORMED.get_property_values("facing"), None);
+        assert_eq!(
+            MALFORMED.get_property_values("powered"),
+            Some(vec!["false".to_string(), "true".to_string()])
+        );
+
+        let state = BlockState::from_default(&MALFORMED).unwrap();
+        assert_eq!(state.get_property("facing"), None);
+        assert_eq!(state.get_property("powered"), Some("false"));
+        assert!(!state.to_string().is_empty());
+    }
+
+    #[test]
+    fn display_names_are_human_readable() {
+        let planks = BLOCKS.get("minecraft:oak_planks").unwrap();
+        assert_eq!(planks.display_name(), "Oak Planks");
+        assert_eq!(crate::display_name("minecraft:oak_planks"), "Oak Planks");
+
+        // Names come from the data where they differ from the ID
+        assert_eq!(crate::display_name("minecraft:gold_block"), "Block of Gold");
+
+        // Unknown IDs fall back to title-casing
+        assert_eq!(
+            crate::display_name("minecraft:made_up_block"),
+            "Made Up Block"
+        );
+    }
+
+    #[test]
+    fn matches_state_accepts_possible_patterns() {
+        let repeater = BLOCKS.get("minecraft:repeater").unwrap();
+
+        assert!(repeater.matches_state("[facing=north]"));
+        assert!(repeater.matches_state("facing=north,delay=4"));
+        assert!(repeater.matches_state("minecraft:repeater[delay=*]"));
+        assert!(repeater.matches_state("repeater"));
+    }
+
+    #[test]
+    fn matches_state_rejects_impossible_patterns() {
+        let repeater = BLOCKS.get("minecraft:repeater").unwrap();
+        let stone = BLOCKS.get("minecraft:stone").unwrap();
+
+        assert!(!repeater.matches_state("[delay=9]"));
+        assert!(!repeater.matches_state("[facing=up]"));
+        assert!(!repeater.matches_state("minecraft:comparator[facing=north]"));
+        assert!(!repeater.matches_state("[facing=north"));
+        assert!(!stone.matches_state("[facing=north]"));
+    }
+
+    #[test]
+    fn set_property_mutates_in_place() {
+        let mut state = BlockState::new("minecraft:repeater").unwrap();
+        state.set_property("delay", "3").unwrap();
+        state.set_property("facing", "east").unwrap();
+        assert_eq!(state.get_property("delay"), Some("3"));
+        assert_eq!(state.get_property("facing"), Some("east"));
+
+        state.set_property("delay", "1").unwrap();
+        assert_eq!(state.get_property("delay"), Some("1"));
+
+        assert_eq!(state.remove_property("facing"), Some("east".to_string()));
+        assert_eq!(state.get_property("facing"), None);
+        assert_eq!(state.remove_property("facing"), None);
+    }
+
+    #[test]
+    fn set_property_failure_leaves_state_unchanged() {
+        let mut state = BlockState::new("minecraft:repeater")
+            .unwrap()
+            .with("delay", "2")
+            .unwrap();
+        let before = state.clone();
+
+        assert!(state.set_property("delay", "9").is_err());
+        assert!(state.set_property("nonexistent", "true").is_err());
+        assert_eq!(state.id(), before.id());
+        assert_eq!(state.properties(), before.properties());
+    }
+
+    #[test]
+    fn properties_complete_requires_every_property() {
+        let partial = BlockState::new("minecraft:repeater")
+            .unwrap()
+            .with("delay", "2")
+            .unwrap();
+        assert!(!partial.properties_complete());
+
+        let full = partial
+            .with("facing", "east")
+            .unwrap()
+            .with("locked", "false")
+            .unwrap()
+            .with("powered", "true")
+            .unwrap();
+        assert!(full.properties_complete());
+
+        // Blocks without properties are trivially complete
+        let stone = BlockState::new("minecraft:stone").unwrap();
+        assert!(stone.properties_complete());
+    }
+
     #[test]
     fn unknown_block_rejected() {
         let result = BlockState::new("minecraft:nonexistent_block");
@@ -577,6 +895,31 @@ mod milestone4_tests {
         assert!(stats.blocks_with_no_properties >= 1); // Stone has no properties
         assert!(stats.average_properties_per_block >= 0.0);
     }
+
+    #[test]
+    fn property_values_union_covers_all_directions() {
+        let facing = property_values_union(&["facing"]);
+        for direction in ["north", "south", "east", "west", "up", "down"] {
+            assert!(facing.contains(direction), "missing {}", direction);
+        }
+
+        let combined = property_values_union(&["facing", "delay"]);
+        assert!(combined.is_superset(&facing));
+        assert!(combined.contains("4"));
+
+        assert!(property_values_union(&["not_a_property"]).is_empty());
+    }
+
+    #[test]
+    fn cached_all_properties_matches_fresh_computation() {
+        let fresh = get_all_properties();
+        let cached = get_all_properties_cached();
+
+        assert_eq!(cached, &fresh);
+        assert!(cached.contains_key("facing"));
+        // Repeated calls hand out the same allocation
+        assert!(std::ptr::eq(cached, get_all_properties_cached()));
+    }
 }
 
 #[cfg(test)]
@@ -731,4 +1074,19 @@ mod bedrock_translation_tests {
             }
         }
     }
+
+    #[test]
+    fn all_mappings_round_trip() {
+        use crate::bedrock_mapping::BedrockBlockStateMapper;
+
+        let mut count = 0;
+        for (java, bedrock) in BedrockBlockStateMapper::all_mappings() {
+            assert_eq!(
+                BedrockBlockStateMapper::java_to_bedrock(java),
+                Some(bedrock)
+            );
+            count += 1;
+        }
+        assert!(count > 0);
+    }
 }
@@ -162,6 +162,40 @@ impl BlockTransforms {
         Self::build_block_state(&base_id, &new_properties)
     }
 
+    /// Reset activation state (powered, open, lit, ...) to its resting value
+    ///
+    /// Only properties the block actually defines are touched, and only when the
+    /// resting value is one the block allows. Everything else is left as-is, including
+    /// `extended` and `locked`, which depend on neighbouring blocks (piston heads,
+    /// powering repeaters) rather than the block's own activation state.
+    pub fn normalize_redstone_state(block_state: &BlockState) -> BlockState {
+        const RESTING_VALUES: &[(&str, &str)] = &[
+            ("powered", "false"),
+            ("open", "false"),
+            ("lit", "false"),
+            ("triggered", "false"),
+            ("power", "0"),
+        ];
+
+        let mut normalized = block_state.clone();
+        let Some(facts) = BLOCKS.get(block_state.id()) else {
+            return normalized;
+        };
+
+        for (property, resting) in RESTING_VALUES {
+            let allowed = facts
+                .get_property_values(property)
+                .is_some_and(|values| values.iter().any(|v| v == resting));
+            if allowed {
+                normalized
+                    .properties
+                    .insert(property.to_string(), resting.to_string());
+            }
+        }
+
+        normalized
+    }
+
     /// Get material variant of a block (e.g., oak_stairs -> stone_stairs)
     pub fn get_material_variant(
         block_state: &BlockState,
@@ -351,9 +385,11 @@ impl BlockTransforms {
             let properties_str = &block_id[bracket_pos + 1..];
 
             if !properties_str.ends_with(']') {
-                return Err(BlockpediaError::parse_failed(
+                return Err(BlockpediaError::parse_failed_at(
                     block_id,
                     "missing closing bracket",
+                    block_id.len(),
+                    &block_id[bracket_pos..],
                 ));
             }
 
@@ -486,6 +522,11 @@ impl BlockState {
         BlockTransforms::rotate_block(self, Rotation::Clockwise270)
     }
 
+    /// Reset powered/open/lit and similar activation state to its resting value
+    pub fn normalize_redstone_state(&self) -> BlockState {
+        BlockTransforms::normalize_redstone_state(self)
+    }
+
     /// Get a material variant of this block (e.g., oak_stairs -> stone_stairs)
     pub fn with_material(&self, material: &str) -> Result<BlockState> {
         BlockTransforms::get_material_variant(self, material)
@@ -81,6 +81,7 @@ pub fn generate_gradient_between_blocks(
         "hsl" => ColorSpace::Hsl,
         "oklab" => ColorSpace::Oklab,
         "lab" => ColorSpace::Lab,
+        "oklch" => ColorSpace::Oklch,
         _ => ColorSpace::Oklab,
     };
 
@@ -175,6 +176,7 @@ pub fn generate_gradient_between_colors(
         "hsl" => ColorSpace::Hsl,
         "oklab" => ColorSpace::Oklab,
         "lab" => ColorSpace::Lab,
+        "oklch" => ColorSpace::Oklch,
         _ => ColorSpace::Oklab,
     };
 
@@ -1,4 +1,6 @@
-use blockpedia::color::block_palettes::{BlockFilter, BlockPaletteGenerator, PaletteTheme};
+use blockpedia::color::block_palettes::{
+    BlockFilter, BlockPaletteGenerator, BlockRole, PaletteTheme,
+};
 use blockpedia::BLOCKS;
 
 #[test]
@@ -307,3 +309,52 @@ fn test_filter_impact_on_block_count() {
     assert!(solid_count > 100); // Should have plenty of solid blocks
     assert!(structural_count > 50); // Should have decent structural options
 }
+
+#[test]
+fn test_palette_from_block_ids() {
+    let palette = blockpedia::color::palette_from_block_ids(
+        &[
+            "minecraft:stone_bricks",
+            "minecraft:oak_planks",
+            "minecraft:gold_block",
+        ],
+        "Keep",
+    )
+    .unwrap();
+
+    assert_eq!(palette.name, "Keep");
+    assert_eq!(palette.theme, PaletteTheme::Custom);
+    let ids: Vec<&str> = palette.blocks.iter().map(|rec| rec.block.id()).collect();
+    assert_eq!(
+        ids,
+        [
+            "minecraft:stone_bricks",
+            "minecraft:oak_planks",
+            "minecraft:gold_block"
+        ]
+    );
+    let roles: Vec<BlockRole> = palette.blocks.iter().map(|rec| rec.role.clone()).collect();
+    assert_eq!(
+        roles,
+        [BlockRole::Primary, BlockRole::Secondary, BlockRole::Accent]
+    );
+
+    assert!(blockpedia::color::palette_from_block_ids(
+        &["minecraft:stone", "minecraft:not_a_block"],
+        "Broken"
+    )
+    .is_err());
+}
+
+#[test]
+fn test_distinct_hue_palette() {
+    let palette = BlockPaletteGenerator::generate_distinct_hue_palette(6).unwrap();
+    assert_eq!(palette.blocks.len(), 6);
+    assert_eq!(palette.theme, PaletteTheme::Custom);
+
+    let ids: std::collections::HashSet<&str> =
+        palette.blocks.iter().map(|rec| rec.block.id()).collect();
+    assert_eq!(ids.len(), 6, "Each hue should map to a different block");
+
+    assert!(BlockPaletteGenerator::generate_distinct_hue_palette(0).is_none());
+}
@@ -688,3 +688,66 @@ fn test_gradient_endpoints_exact_color_matching() {
 //         }
 //     }
 // }
+
+#[test]
+fn test_gamma_correct_rgb_midpoint_is_brighter() {
+    let red = ExtendedColorData::from_rgb(255, 0, 0);
+    let green = ExtendedColorData::from_rgb(0, 255, 0);
+
+    let naive = [127u8, 127, 0];
+    let midpoint = red.lerp_gamma_correct(&green, 0.5);
+
+    assert!(
+        midpoint.rgb[0] > naive[0],
+        "red channel: {:?}",
+        midpoint.rgb
+    );
+    assert!(
+        midpoint.rgb[1] > naive[1],
+        "green channel: {:?}",
+        midpoint.rgb
+    );
+    assert_eq!(midpoint.rgb[2], 0);
+
+    // Endpoints are preserved exactly
+    assert_eq!(red.lerp_gamma_correct(&green, 0.0).rgb, red.rgb);
+    assert_eq!(red.lerp_gamma_correct(&green, 1.0).rgb, green.rgb);
+}
+
+#[test]
+fn test_gradient_config_presets() {
+    let smooth = GradientConfig::smooth();
+    assert_eq!(smooth.color_space, ColorSpace::Oklab);
+    assert_eq!(smooth.easing, EasingFunction::EaseInOut);
+    assert_eq!(smooth.steps, GradientConfig::default().steps);
+
+    let vivid = GradientConfig::vivid();
+    assert_eq!(vivid.color_space, ColorSpace::Oklch);
+    assert_eq!(vivid.easing, EasingFunction::Linear);
+
+    let quick = GradientConfig::quick(4);
+    assert_eq!(quick.steps, 4);
+    assert_eq!(quick.color_space, ColorSpace::Rgb);
+    assert_eq!(quick.easing, EasingFunction::Linear);
+}
+
+#[test]
+fn test_oklch_gradient_keeps_chroma() {
+    let color_of = |id: &str| get_block(id).unwrap().extras.color.unwrap().to_extended();
+    let start = color_of("minecraft:orange_wool");
+    let end = color_of("minecraft:light_blue_wool");
+
+    let midpoint_chroma = |color_space: ColorSpace| {
+        let config = GradientConfig::new(3).with_color_space(color_space);
+        let blocks = AllBlocks::new()
+            .with_color()
+            .generate_gradient_between_colors(start, end, config)
+            .collect();
+        assert_eq!(blocks.len(), 3);
+        let middle = blocks[1].extras.color.unwrap().to_extended();
+        middle.oklch[1]
+    };
+
+    // Oklab cuts straight through gray; Oklch goes around the hue wheel
+    assert!(midpoint_chroma(ColorSpace::Oklch) > midpoint_chroma(ColorSpace::Oklab));
+}
@@ -0,0 +1,42 @@
+use blockpedia::color::dither_image_to_blocks;
+use blockpedia::*;
+use image::{DynamicImage, Rgb, RgbImage};
+use std::collections::HashSet;
+
+fn horizontal_gradient(width: u32, height: u32) -> DynamicImage {
+    let img = RgbImage::from_fn(width, height, |x, _| {
+        let v = (x * 255 / (width - 1)) as u8;
+        Rgb([v, v, v])
+    });
+    DynamicImage::ImageRgb8(img)
+}
+
+#[test]
+fn test_dithered_gradient_mixes_blocks() {
+    let palette = [
+        get_block("minecraft:black_concrete").unwrap(),
+        get_block("minecraft:white_concrete").unwrap(),
+    ];
+    let img = horizontal_gradient(64, 16);
+
+    let grid = dither_image_to_blocks(&img, &palette).unwrap();
+    assert_eq!(grid.len(), 16);
+    assert!(grid.iter().all(|row| row.len() == 64));
+
+    // Every 16-column region of a smooth gradient should be a mix of both blocks,
+    // where per-pixel nearest matching would produce two flat bands
+    for region in 0..4 {
+        let used: HashSet<&str> = grid
+            .iter()
+            .flat_map(|row| row[region * 16..(region + 1) * 16].iter().copied())
+            .collect();
+        assert!(used.len() > 1, "region {} used only {:?}", region, used);
+    }
+}
+
+#[test]
+fn test_dithering_requires_colored_palette() {
+    let img = horizontal_gradient(4, 4);
+    let air = get_block("minecraft:air").unwrap();
+    assert!(dither_image_to_blocks(&img, &[air]).is_err());
+}
@@ -277,6 +277,31 @@ fn test_complementary_colors() {
     assert_eq!(unique_colors.len(), 4, "Should have 4 unique colors");
 }
 
+#[test]
+fn test_distinct_hues() {
+    let hues = PaletteGenerator::generate_distinct_hues(6);
+    assert_eq!(hues.len(), 6);
+
+    for (i, color) in hues.iter().enumerate() {
+        let expected = i as f32 * 60.0;
+        assert!(
+            (color.hsl[0] - expected).abs() < 1.0,
+            "hue {} should be {}°, got {}°",
+            i,
+            expected,
+            color.hsl[0]
+        );
+    }
+
+    // Saturation and lightness are shared across the set
+    assert!(hues
+        .windows(2)
+        .all(|pair| (pair[0].hsl[1] - pair[1].hsl[1]).abs() < 0.02
+            && (pair[0].hsl[2] - pair[1].hsl[2]).abs() < 0.02));
+
+    assert!(PaletteGenerator::generate_distinct_hues(0).is_empty());
+}
+
 #[cfg(test)]
 mod integration_tests {
     use super::*;
@@ -148,6 +148,116 @@ fn test_exclusion_filters() {
     );
 }
 
+#[test]
+fn test_obtainability() {
+    let bedrock = get_block("minecraft:bedrock").unwrap();
+    let command_block = get_block("minecraft:command_block").unwrap();
+    let stone = get_block("minecraft:stone").unwrap();
+
+    // Both are unbreakable according to the block data
+    assert_eq!(bedrock.extras.obtainable, Some(false));
+    assert_eq!(command_block.extras.obtainable, Some(false));
+
+    assert_eq!(bedrock.obtainability(), Obtainability::CreativeOnly);
+    assert_eq!(command_block.obtainability(), Obtainability::Unobtainable);
+    assert_eq!(stone.obtainability(), Obtainability::Survival);
+
+    let survival: Vec<&str> = AllBlocks::new()
+        .survival_only()
+        .collect()
+        .into_iter()
+        .map(|b| b.id())
+        .collect();
+    assert!(!survival.contains(&"minecraft:bedrock"));
+    assert!(!survival.contains(&"minecraft:command_block"));
+    assert!(survival.contains(&"minecraft:stone"));
+}
+
+#[test]
+fn test_interactive_blocks() {
+    let crafting_table = get_block("minecraft:crafting_table").unwrap();
+    let stone = get_block("minecraft:stone").unwrap();
+    let button = get_block("minecraft:oak_button").unwrap();
+
+    assert!(crafting_table.is_interactive());
+    assert!(!stone.is_interactive());
+    // Buttons are not tile entities but still react to right-clicks
+    assert!(button.is_interactive());
+    assert!(get_block("minecraft:oak_door").unwrap().is_interactive());
+    // Iron doors and trapdoors only react to redstone
+    assert!(!get_block("minecraft:iron_door").unwrap().is_interactive());
+    assert!(!get_block("minecraft:iron_trapdoor")
+        .unwrap()
+        .is_interactive());
+
+    let remaining: Vec<&str> = AllBlocks::new()
+        .exclude_interactive()
+        .collect()
+        .into_iter()
+        .map(|b| b.id())
+        .collect();
+    assert!(!remaining.contains(&"minecraft:crafting_table"));
+    assert!(!remaining.contains(&"minecraft:oak_button"));
+    assert!(!remaining.contains(&"minecraft:chest"));
+    assert!(remaining.contains(&"minecraft:stone"));
+}
+
+#[test]
+fn test_block_categories() {
+    let cases = [
+        ("minecraft:stone_bricks", BlockCategory::Stone),
+        ("minecraft:iron_ore", BlockCategory::Stone),
+        ("minecraft:oak_planks", BlockCategory::Wood),
+        ("minecraft:spruce_log", BlockCategory::Wood),
+        ("minecraft:oak_stairs", BlockCategory::Wood),
+        ("minecraft:oak_hanging_sign", BlockCategory::Wood),
+        ("minecraft:crimson_stem", BlockCategory::Wood),
+        ("minecraft:white_concrete", BlockCategory::Concrete),
+        ("minecraft:red_terracotta", BlockCategory::Terracotta),
+        ("minecraft:blue_wool", BlockCategory::Wool),
+        ("minecraft:blue_carpet", BlockCategory::Wool),
+        ("minecraft:glass", BlockCategory::Glass),
+        ("minecraft:red_stained_glass_pane", BlockCategory::Glass),
+        ("minecraft:iron_block", BlockCategory::Metal),
+        ("minecraft:gold_block", BlockCategory::Metal),
+        ("minecraft:oak_leaves", BlockCategory::Plant),
+        ("minecraft:poppy", BlockCategory::Plant),
+        ("minecraft:moss_carpet", BlockCategory::Plant),
+        ("minecraft:redstone_wire", BlockCategory::Other),
+        ("minecraft:redstone_lamp", BlockCategory::Other),
+        ("minecraft:crafting_table", BlockCategory::Other),
+    ];
+    for (id, expected) in cases {
+        assert_eq!(get_block(id).unwrap().category(), expected, "{}", id);
+    }
+
+    let wool = AllBlocks::new().in_category(BlockCategory::Wool).collect();
+    let ids: Vec<&str> = wool.iter().map(|b| b.id).collect();
+    assert!(ids.contains(&"minecraft:red_wool"));
+    assert!(!ids.contains(&"minecraft:stone"));
+    assert!(wool.iter().all(|b| b.category() == BlockCategory::Wool));
+}
+
+#[test]
+fn test_exclude_category() {
+    let remaining = AllBlocks::new()
+        .exclude_category(&[BlockCategory::Plant, BlockCategory::Glass])
+        .collect();
+    let ids: Vec<&str> = remaining.iter().map(|b| b.id).collect();
+
+    assert!(!ids.contains(&"minecraft:oak_leaves"));
+    assert!(!ids.contains(&"minecraft:poppy"));
+    assert!(!ids.contains(&"minecraft:glass"));
+    assert!(!ids.contains(&"minecraft:white_stained_glass_pane"));
+    assert!(ids.contains(&"minecraft:stone"));
+    assert!(ids.contains(&"minecraft:oak_planks"));
+    assert!(remaining
+        .iter()
+        .all(|b| !matches!(b.category(), BlockCategory::Plant | BlockCategory::Glass)));
+
+    assert_eq!(AllBlocks::new().exclude_category(&[]).len(), BLOCKS.len());
+}
+
 #[test]
 fn test_sorting() {
     let query = AllBlocks::new().with_color().limit(10);
@@ -169,6 +279,91 @@ fn test_sorting() {
     );
 }
 
+/// Straightforward single-threaded orderings the optimized sorts must reproduce exactly
+fn reference_similarity_order(reference: ExtendedColorData) -> Vec<&'static str> {
+    let mut blocks = AllBlocks::new().collect();
+    blocks.sort_by(|a, b| {
+        let dist = |block: &BlockFacts| {
+            block
+                .extras
+                .color
+                .map(|c| c.to_extended().distance_oklab(&reference))
+                .unwrap_or(f32::INFINITY)
+        };
+        dist(a)
+            .partial_cmp(&dist(b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    blocks.into_iter().map(|b| b.id()).collect()
+}
+
+fn reference_gradient_order() -> Vec<&'static str> {
+    let mut remaining = AllBlocks::new().with_color().collect();
+    let mut result = vec![remaining.remove(0)];
+    while !remaining.is_empty() {
+        let current = result.last().unwrap().extras.color.unwrap().to_extended();
+        let mut best_index = 0;
+        let mut best_distance = f32::INFINITY;
+        for (i, block) in remaining.iter().enumerate() {
+            let distance = block
+                .extras
+                .color
+                .unwrap()
+                .to_extended()
+                .distance_oklab(&current);
+            if distance < best_distance {
+                best_distance = distance;
+                best_index = i;
+            }
+        }
+        result.push(remaining.remove(best_index));
+    }
+    result.into_iter().map(|b| b.id()).collect()
+}
+
+fn ids(query: BlockQuery) -> Vec<&'static str> {
+    query.collect().into_iter().map(|b| b.id()).collect()
+}
+
+#[test]
+fn test_color_sorts_match_reference_order() {
+    let reference = ExtendedColorData::from_rgb(200, 60, 40);
+
+    assert_eq!(
+        ids(AllBlocks::new().sort_by_color_similarity(reference)),
+        reference_similarity_order(reference)
+    );
+    assert_eq!(
+        ids(AllBlocks::new().with_color().sort_by_color_gradient()),
+        reference_gradient_order()
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_color_sorts_identical_across_thread_counts() {
+    let reference = ExtendedColorData::from_rgb(200, 60, 40);
+    let expected_similarity = reference_similarity_order(reference);
+    let expected_gradient = reference_gradient_order();
+
+    for threads in [1, 2, 4, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+
+        let (similarity, gradient) = pool.install(|| {
+            (
+                ids(AllBlocks::new().sort_by_color_similarity(reference)),
+                ids(AllBlocks::new().with_color().sort_by_color_gradient()),
+            )
+        });
+
+        assert_eq!(similarity, expected_similarity, "{} threads", threads);
+        assert_eq!(gradient, expected_gradient, "{} threads", threads);
+    }
+}
+
 #[test]
 fn test_color_similarity() {
     let query = AllBlocks::new().with_color();
@@ -192,6 +387,140 @@ fn test_color_similarity() {
     }
 }
 
+#[test]
+fn test_restrict_to_colors() {
+    let color_of = |id: &str| {
+        get_block(id)
+            .unwrap()
+            .extras
+            .color
+            .expect("wool has color data")
+            .to_extended()
+    };
+    let palette = [
+        color_of("minecraft:red_wool"),
+        color_of("minecraft:white_wool"),
+    ];
+    let tolerance = 0.02;
+
+    let restricted = AllBlocks::new().restrict_to_colors(&palette, tolerance);
+    let ids: Vec<&str> = restricted
+        .clone()
+        .collect()
+        .iter()
+        .map(|b| b.id())
+        .collect();
+
+    assert!(ids.contains(&"minecraft:red_wool"));
+    assert!(ids.contains(&"minecraft:white_wool"));
+    assert!(!ids.contains(&"minecraft:black_wool"));
+
+    for block in restricted.collect() {
+        let color = block.extras.color.expect("colorless blocks are excluded");
+        let color = color.to_extended();
+        assert!(palette
+            .iter()
+            .any(|target| color.distance_oklab(target) <= tolerance));
+    }
+
+    assert!(AllBlocks::new().restrict_to_colors(&[], 1.0).is_empty());
+}
+
+#[test]
+fn test_collect_with_colors() {
+    // Blocks starting with "a": acacia planks are colored, air is not
+    let mixed = AllBlocks::new().matching("minecraft:a");
+    let total = mixed.len();
+    let colored = mixed.clone().with_color().len();
+    assert!(colored > 0 && colored < total);
+
+    let pairs = mixed.collect_with_colors();
+    assert_eq!(pairs.len(), colored);
+    for (block, color) in pairs {
+        assert_ne!(block.id(), "minecraft:air");
+        assert_eq!(color.rgb, block.extras.color.unwrap().to_extended().rgb);
+    }
+}
+
+#[test]
+fn test_perceived_brightness() {
+    let green = ExtendedColorData::from_rgb(0, 255, 0);
+    let blue = ExtendedColorData::from_rgb(0, 0, 255);
+    assert!(green.perceived_brightness() > blue.perceived_brightness());
+    assert_eq!(
+        ExtendedColorData::from_rgb(0, 0, 0).perceived_brightness(),
+        0.0
+    );
+    assert!((ExtendedColorData::from_rgb(255, 255, 255).perceived_brightness() - 1.0).abs() < 1e-6);
+
+    let bright = AllBlocks::new().brighter_than(0.8).collect();
+    let dark = AllBlocks::new().darker_than(0.2).collect();
+    assert!(!bright.is_empty() && !dark.is_empty());
+    for block in bright {
+        assert!(
+            block
+                .extras
+                .color
+                .unwrap()
+                .to_extended()
+                .perceived_brightness()
+                > 0.8
+        );
+    }
+    for block in dark {
+        assert!(
+            block
+                .extras
+                .color
+                .unwrap()
+                .to_extended()
+                .perceived_brightness()
+                < 0.2
+        );
+    }
+}
+
+#[test]
+fn test_color_coverage() {
+    assert_eq!(AllBlocks::new().with_color().color_coverage(), 1.0);
+
+    let colored = all_blocks().filter(|b| b.extras.color.is_some()).count();
+    let expected = colored as f32 / BLOCKS.len() as f32;
+    let all = AllBlocks::new();
+    assert!((all.color_coverage() - expected).abs() < f32::EPSILON);
+    assert!(all.color_coverage() < 1.0);
+
+    // Callable mid-chain without consuming the query
+    assert_eq!(all.len(), BLOCKS.len());
+    assert_eq!(AllBlocks::new().limit(0).color_coverage(), 0.0);
+}
+
+#[test]
+fn test_split_by_color_temperature() {
+    let wool = AllBlocks::new().matching("minecraft:*_wool");
+    let wool_count = wool.len();
+    let groups = wool.split_by_color_temperature();
+    let ids = |group: &[&BlockFacts]| group.iter().map(|b| b.id).collect::<Vec<_>>();
+
+    let warm = ids(&groups.warm);
+    let neutral = ids(&groups.neutral);
+    let cool = ids(&groups.cool);
+    assert!(warm.contains(&"minecraft:red_wool"));
+    assert!(warm.contains(&"minecraft:orange_wool"));
+    assert!(cool.contains(&"minecraft:blue_wool"));
+    assert!(cool.contains(&"minecraft:light_blue_wool"));
+    assert!(neutral.contains(&"minecraft:white_wool"));
+    assert!(neutral.contains(&"minecraft:gray_wool"));
+    assert!(groups.uncolored.is_empty());
+    assert_eq!(warm.len() + neutral.len() + cool.len(), wool_count);
+
+    let air = AllBlocks::new()
+        .matching("minecraft:air")
+        .split_by_color_temperature();
+    assert_eq!(ids(&air.uncolored), ["minecraft:air"]);
+    assert!(air.warm.is_empty() && air.neutral.is_empty() && air.cool.is_empty());
+}
+
 #[test]
 fn test_limit_and_offset() {
     let query = AllBlocks::new();
@@ -511,3 +840,128 @@ fn test_realistic_use_cases() {
     // May or may not find blocks depending on test data
     // This tests the pattern matching works without error
 }
+
+#[test]
+fn test_color_swatches() {
+    let orange = ExtendedColorData::from_rgb(255, 128, 0);
+    assert_eq!(orange.to_ansi_truecolor(), "\x1b[48;2;255;128;0m  \x1b[0m");
+
+    #[cfg(feature = "tui")]
+    assert_eq!(
+        orange.to_ratatui_color(),
+        ratatui::style::Color::Rgb(255, 128, 0)
+    );
+}
+
+#[test]
+fn test_to_markdown_table() {
+    let log = BLOCKS.get("minecraft:oak_log").unwrap();
+    let hex = log.extras.color.unwrap().to_extended().hex_string();
+
+    let table = AllBlocks::new()
+        .matching("minecraft:oak_log")
+        .to_markdown_table();
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines[0], "| ID | Color | Properties |");
+    assert_eq!(lines[1], "|---|---|---|");
+    assert_eq!(
+        lines[2],
+        format!("| minecraft:oak_log | {} | {} |", hex, log.properties.len())
+    );
+    assert_eq!(lines.len(), 3);
+
+    let air = AllBlocks::new()
+        .matching("minecraft:air")
+        .to_markdown_table();
+    assert!(air.ends_with("| minecraft:air | - | 0 |\n"));
+}
+
+#[test]
+fn test_nearest_dye_blocks() {
+    use blockpedia::color::{nearest_concrete_block, nearest_terracotta_block, nearest_wool_block};
+
+    let red = ExtendedColorData::from_rgb(255, 0, 0);
+    assert_eq!(nearest_wool_block(red).unwrap().id, "minecraft:red_wool");
+    assert_eq!(
+        nearest_concrete_block(red).unwrap().id,
+        "minecraft:red_concrete"
+    );
+    assert!(nearest_terracotta_block(red)
+        .unwrap()
+        .id
+        .ends_with("terracotta"));
+
+    let white = ExtendedColorData::from_rgb(255, 255, 255);
+    assert_eq!(
+        nearest_wool_block(white).unwrap().id,
+        "minecraft:white_wool"
+    );
+}
+
+#[test]
+fn test_first_n_distinct_colors() {
+    // Oak blocks share the same texture color, so a plain limit returns near-duplicates
+    let oak = AllBlocks::new()
+        .matching("minecraft:oak_*")
+        .with_color()
+        .sort_by_name();
+    let limited = oak.clone().limit(5).collect_with_colors();
+    assert!(limited
+        .windows(2)
+        .any(|pair| pair[0].1.distance_oklab(&pair[1].1) < DISTINCT_COLOR_MIN_DISTANCE));
+
+    let distinct = oak.first_n_distinct_colors(5);
+    assert!(!distinct.is_empty() && distinct.len() <= 5);
+    for (i, a) in distinct.iter().enumerate() {
+        for b in &distinct[i + 1..] {
+            let a = a.extras.color.unwrap().to_extended();
+            let b = b.extras.color.unwrap().to_extended();
+            assert!(a.distance_oklab(&b) >= DISTINCT_COLOR_MIN_DISTANCE);
+        }
+    }
+
+    // Distinct wool colors fill the request
+    let wool = AllBlocks::new()
+        .matching("minecraft:*_wool")
+        .first_n_distinct_colors(8);
+    assert_eq!(wool.len(), 8);
+    assert!(AllBlocks::new().first_n_distinct_colors(0).is_empty());
+}
+
+#[test]
+fn test_block_info() {
+    let info = block_info("minecraft:oak_planks").unwrap();
+    let planks = get_block("minecraft:oak_planks").unwrap();
+
+    assert_eq!(info.id, "minecraft:oak_planks");
+    assert_eq!(info.display_name, "Oak Planks");
+    assert_eq!(info.category, BlockCategory::Wood);
+    assert!(info.properties.is_empty());
+    assert_eq!(info.default_state.to_string(), "minecraft:oak_planks");
+    assert!(!info.transparent);
+    assert_eq!(info.hardness, Some(2.0));
+    assert!(!info.light_source);
+    assert!(!info.interactive);
+    assert_eq!(info.obtainability, Obtainability::Survival);
+
+    let color = info.color.expect("oak planks have color data");
+    assert_eq!(color.color.rgb, planks.extras.color.unwrap().rgb);
+    assert_eq!(color.hex, color.color.hex_string());
+    assert!(color.nearest_dye.is_some());
+
+    assert!(info.variants.contains(&"minecraft:oak_stairs"));
+    assert!(info.variants.contains(&"minecraft:oak_slab"));
+    assert!(info.variants.contains(&"minecraft:oak_fence"));
+    assert!(!info.variants.contains(&"minecraft:oak_planks"));
+
+    // Stateful blocks get a complete default state
+    let repeater = block_info("minecraft:repeater").unwrap();
+    assert!(repeater.default_state.properties_complete());
+    assert!(repeater.color.is_none());
+
+    // Unbreakable blocks report negative hardness
+    let bedrock = block_info("minecraft:bedrock").unwrap();
+    assert!(bedrock.hardness.unwrap() < 0.0);
+
+    assert!(block_info("minecraft:not_a_block").is_none());
+}
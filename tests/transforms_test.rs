@@ -353,3 +353,40 @@ fn test_convenience_methods() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_normalize_redstone_state() -> Result<()> {
+    let lever = BlockState::parse("minecraft:lever[face=wall,facing=east,powered=true]")?;
+    let normalized = lever.normalize_redstone_state();
+
+    assert_eq!(normalized.get_property("powered"), Some("false"));
+    // Unrelated properties are preserved
+    assert_eq!(normalized.get_property("face"), Some("wall"));
+    assert_eq!(normalized.get_property("facing"), Some("east"));
+
+    let door = BlockState::parse("minecraft:oak_door[open=true,powered=true,half=upper]")?;
+    let normalized = door.normalize_redstone_state();
+    assert_eq!(normalized.get_property("open"), Some("false"));
+    assert_eq!(normalized.get_property("powered"), Some("false"));
+    assert_eq!(normalized.get_property("half"), Some("upper"));
+
+    // Neighbour-derived state is left alone
+    let repeater = BlockState::parse("minecraft:repeater[locked=true,powered=true]")?;
+    let normalized = repeater.normalize_redstone_state();
+    assert_eq!(normalized.get_property("powered"), Some("false"));
+    assert_eq!(normalized.get_property("locked"), Some("true"));
+
+    let piston = BlockState::parse("minecraft:piston[extended=true,facing=up]")?;
+    assert_eq!(
+        piston.normalize_redstone_state().get_property("extended"),
+        Some("true")
+    );
+
+    let stone = BlockState::parse("minecraft:stone")?;
+    assert_eq!(
+        stone.normalize_redstone_state().to_string(),
+        stone.to_string()
+    );
+
+    Ok(())
+}